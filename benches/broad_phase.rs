@@ -0,0 +1,65 @@
+use std::{hint::black_box, time::{Duration, Instant}};
+
+use opengl_pong::{find_collision, find_collisions, Collider, ColliderWorld};
+
+const ITERATIONS: u32 = 20;
+
+fn build_scene(brick_count: usize) -> ColliderWorld {
+  let mut colliders = ColliderWorld::new();
+  let columns = 40;
+
+  for brick_index in 0..brick_count {
+    let column = brick_index % columns;
+    let row = brick_index / columns;
+
+    colliders.insert(Collider::new_static(column as f32 * 20.0, row as f32 * 10.0, 18.0, 8.0));
+  }
+
+  for ball_index in 0..8 {
+    colliders.insert(Collider::new(ball_index as f32 * 97.0, ball_index as f32 * 13.0, 16.0, 16.0));
+  }
+
+  colliders
+}
+
+fn find_collisions_brute_force(colliders: &ColliderWorld) -> usize {
+  let mut collision_count = 0;
+
+  for (primary_handle, primary) in colliders.iter() {
+    for (secondary_handle, secondary) in colliders.iter() {
+      if primary_handle != secondary_handle && find_collision(primary_handle, secondary_handle, primary, secondary).is_some() {
+        collision_count += 1;
+      }
+    }
+  }
+
+  collision_count
+}
+
+fn measure<F: FnMut()>(mut operation: F) -> Duration {
+  let start_time = Instant::now();
+
+  for _ in 0..ITERATIONS {
+    operation();
+  }
+
+  (Instant::now() - start_time) / ITERATIONS
+}
+
+fn main() {
+  println!("{:>8} {:>16} {:>16}", "bricks", "brute force", "broad phase");
+
+  for brick_count in [100, 200, 400, 800, 1600, 3200] {
+    let colliders = build_scene(brick_count);
+
+    let brute_force_duration = measure(|| {
+      black_box(find_collisions_brute_force(black_box(&colliders)));
+    });
+
+    let broad_phase_duration = measure(|| {
+      black_box(find_collisions(black_box(&colliders)));
+    });
+
+    println!("{:>8} {:>16?} {:>16?}", brick_count, brute_force_duration, broad_phase_duration);
+  }
+}
\ No newline at end of file
@@ -1,11 +1,13 @@
 #version 330 core
 
 in vec2 textureCoords;
+in vec4 spriteColor;
 
 out vec4 color;
 
 uniform sampler2D tex;
+uniform vec4 tint;
 
 void main() {
-  color = texture(tex, textureCoords);
+  color = texture(tex, textureCoords) * spriteColor * tint;
 }
\ No newline at end of file
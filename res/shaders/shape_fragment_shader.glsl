@@ -0,0 +1,9 @@
+#version 330 core
+
+in vec4 shapeColor;
+
+out vec4 color;
+
+void main() {
+  color = shapeColor;
+}
\ No newline at end of file
@@ -0,0 +1,15 @@
+#version 330 core
+
+layout(location = 0) in vec2 pos;
+layout(location = 1) in vec4 vertexColor;
+
+out vec4 shapeColor;
+
+uniform mat4 model;
+uniform mat4 view;
+uniform mat4 projection;
+
+void main() {
+  gl_Position = projection * view * model * vec4(pos, 0.0, 1.0);
+  shapeColor = vertexColor;
+}
\ No newline at end of file
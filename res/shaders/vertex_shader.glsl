@@ -2,8 +2,10 @@
 
 layout(location = 0) in vec2 pos;
 layout(location = 1) in vec2 texturePos;
+layout(location = 2) in vec4 vertexColor;
 
 out vec2 textureCoords;
+out vec4 spriteColor;
 
 uniform mat4 model;
 uniform mat4 view;
@@ -12,4 +14,5 @@ uniform mat4 projection;
 void main() {
   gl_Position =  projection * view * model * vec4(pos, 0.0, 1.0);
   textureCoords = texturePos;
+  spriteColor = vertexColor;
 }
\ No newline at end of file
@@ -0,0 +1,157 @@
+use crate::prelude::{AnimationFrame, AnimationMode, Sprite};
+
+pub struct Animation {
+  frames: Vec<AnimationFrame>,
+  mode: AnimationMode
+}
+
+impl Animation {
+  pub fn new(frames: Vec<AnimationFrame>, mode: AnimationMode) -> Self {
+    Self { frames, mode }
+  }
+
+  pub fn from_sheet(sheet: Sprite, columns: u32, rows: u32, frame_duration: f32, mode: AnimationMode) -> Self {
+    let frames = sheet
+      .split_grid(columns, rows)
+      .into_iter()
+      .map(|frame| AnimationFrame::new(frame, frame_duration))
+      .collect();
+
+    Self::new(frames, mode)
+  }
+
+  pub fn frames(&self) -> &[AnimationFrame] {
+    &self.frames
+  }
+
+  pub fn mode(&self) -> AnimationMode {
+    self.mode
+  }
+
+  pub fn cycle_duration(&self) -> f32 {
+    self.sequence().map(|index| self.frames[index].duration()).sum()
+  }
+
+  pub fn is_finished(&self, elapsed: f32) -> bool {
+    self.mode == AnimationMode::Once && elapsed >= self.cycle_duration()
+  }
+
+  pub fn frame_index(&self, elapsed: f32) -> usize {
+    let cycle_duration = self.cycle_duration();
+
+    if self.frames.is_empty() || cycle_duration <= 0.0 {
+      return 0;
+    }
+
+    if self.is_finished(elapsed) {
+      return self.frames.len() - 1;
+    }
+
+    let mut remaining = elapsed.max(0.0) % cycle_duration;
+
+    for index in self.sequence() {
+      let duration = self.frames[index].duration();
+
+      if remaining < duration {
+        return index;
+      }
+
+      remaining -= duration;
+    }
+
+    self.frames.len() - 1
+  }
+
+  pub fn sprite_at(&self, elapsed: f32) -> Option<Sprite> {
+    self.frames.get(self.frame_index(elapsed)).map(AnimationFrame::sprite)
+  }
+
+  fn sequence(&self) -> impl Iterator<Item = usize> {
+    let frame_count = self.frames.len();
+
+    let returning_frames = match self.mode {
+      AnimationMode::PingPong if frame_count > 2 => frame_count - 2,
+      _ => 0
+    };
+
+    (0..frame_count).chain((1..=returning_frames).rev())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::prelude::{Rect, Vector2};
+
+  fn animation(frame_count: u32, mode: AnimationMode) -> Animation {
+    Animation::from_sheet(Sprite::full(64.0, 16.0), frame_count, 1, 100.0, mode)
+  }
+
+  #[test]
+  fn sheet_frames() {
+    let animation = Animation::from_sheet(Sprite::full(64.0, 32.0), 4, 2, 50.0, AnimationMode::Loop);
+
+    assert_eq!(animation.frames().len(), 8);
+
+    let frame = animation.frames()[5];
+    assert_eq!(frame.duration(), 50.0);
+    assert_eq!(frame.sprite().width(), 16.0);
+    assert_eq!(frame.sprite().height(), 16.0);
+    assert_eq!(frame.sprite().uv(), Rect::from_corners(Vector2::new(0.25, 0.5), Vector2::new(0.5, 1.0)));
+  }
+
+  #[test]
+  fn looping() {
+    let animation = animation(4, AnimationMode::Loop);
+
+    assert_eq!(animation.cycle_duration(), 400.0);
+    assert_eq!(animation.frame_index(0.0), 0);
+    assert_eq!(animation.frame_index(150.0), 1);
+    assert_eq!(animation.frame_index(399.0), 3);
+    assert_eq!(animation.frame_index(400.0), 0);
+    assert_eq!(animation.frame_index(1250.0), 0);
+    assert!(!animation.is_finished(10000.0));
+  }
+
+  #[test]
+  fn ping_pong() {
+    let animation = animation(4, AnimationMode::PingPong);
+
+    let indices: Vec<usize> = (0..8).map(|step| animation.frame_index((step as f32 * 100.0) + 50.0)).collect();
+
+    assert_eq!(animation.cycle_duration(), 600.0);
+    assert_eq!(indices, vec![0, 1, 2, 3, 2, 1, 0, 1]);
+  }
+
+  #[test]
+  fn once() {
+    let animation = animation(3, AnimationMode::Once);
+
+    assert_eq!(animation.frame_index(250.0), 2);
+    assert!(!animation.is_finished(299.0));
+    assert!(animation.is_finished(300.0));
+    assert_eq!(animation.frame_index(1000.0), 2);
+  }
+
+  #[test]
+  fn varying_durations() {
+    let sprite = Sprite::full(16.0, 16.0);
+    let animation = Animation::new(
+      vec![AnimationFrame::new(sprite, 10.0), AnimationFrame::new(sprite, 90.0)],
+      AnimationMode::Loop
+    );
+
+    assert_eq!(animation.frame_index(5.0), 0);
+    assert_eq!(animation.frame_index(15.0), 1);
+    assert_eq!(animation.frame_index(99.0), 1);
+    assert_eq!(animation.frame_index(105.0), 0);
+  }
+
+  #[test]
+  fn empty() {
+    let animation = Animation::new(Vec::new(), AnimationMode::Loop);
+
+    assert_eq!(animation.frame_index(100.0), 0);
+    assert!(animation.sprite_at(100.0).is_none());
+  }
+}
\ No newline at end of file
@@ -0,0 +1,21 @@
+use crate::prelude::Sprite;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct AnimationFrame {
+  sprite: Sprite,
+  duration: f32
+}
+
+impl AnimationFrame {
+  pub fn new(sprite: Sprite, duration: f32) -> Self {
+    Self { sprite, duration }
+  }
+
+  pub fn sprite(&self) -> Sprite {
+    self.sprite
+  }
+
+  pub fn duration(&self) -> f32 {
+    self.duration
+  }
+}
\ No newline at end of file
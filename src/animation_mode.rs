@@ -0,0 +1,6 @@
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AnimationMode {
+  Loop,
+  PingPong,
+  Once
+}
\ No newline at end of file
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use crate::prelude::{Animation, AnimationMode, Sprite};
+
+#[derive(Default)]
+pub struct AnimationPlayer {
+  animations: HashMap<String, Animation>,
+  current: Option<String>,
+  elapsed: f32
+}
+
+impl AnimationPlayer {
+  pub fn add(&mut self, name: &str, animation: Animation) {
+    self.animations.insert(name.to_string(), animation);
+  }
+
+  pub fn play(&mut self, name: &str) -> Result<(), String> {
+    if !self.animations.contains_key(name) {
+      return Err(format!("Unknown animation '{name}'"));
+    }
+
+    if self.current.as_deref() != Some(name) {
+      self.current = Some(name.to_string());
+      self.elapsed = 0.0;
+    }
+
+    Ok(())
+  }
+
+  pub fn restart(&mut self) {
+    self.elapsed = 0.0;
+  }
+
+  pub fn advance(&mut self, deltamillis: f32) {
+    let Some(animation) = self.current_animation() else {
+      return;
+    };
+
+    let cycle_duration = animation.cycle_duration();
+    let elapsed = self.elapsed + deltamillis;
+
+    self.elapsed = if cycle_duration <= 0.0 {
+      0.0
+    } else if animation.mode() == AnimationMode::Once {
+      elapsed.min(cycle_duration)
+    } else {
+      elapsed.rem_euclid(cycle_duration)
+    };
+  }
+
+  pub fn current(&self) -> Option<&str> {
+    self.current.as_deref()
+  }
+
+  pub fn elapsed(&self) -> f32 {
+    self.elapsed
+  }
+
+  pub fn sprite(&self) -> Option<Sprite> {
+    self.current_animation()?.sprite_at(self.elapsed)
+  }
+
+  pub fn is_finished(&self) -> bool {
+    self.current_animation().is_some_and(|animation| animation.is_finished(self.elapsed))
+  }
+
+  fn current_animation(&self) -> Option<&Animation> {
+    self.animations.get(self.current.as_deref()?)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn player() -> AnimationPlayer {
+    let mut player = AnimationPlayer::default();
+    player.add("pulse", Animation::from_sheet(Sprite::full(64.0, 16.0), 4, 1, 100.0, AnimationMode::PingPong));
+    player.add("flash", Animation::from_sheet(Sprite::full(32.0, 16.0), 2, 1, 50.0, AnimationMode::Once));
+    player
+  }
+
+  #[test]
+  fn playing() {
+    let mut player = player();
+
+    assert!(player.sprite().is_none());
+    player.advance(100.0);
+    assert_eq!(player.elapsed(), 0.0);
+
+    player.play("pulse").unwrap();
+    player.advance(250.0);
+
+    assert_eq!(player.current(), Some("pulse"));
+    assert_eq!(player.sprite().unwrap().uv().left(), 0.5);
+
+    player.play("pulse").unwrap();
+    assert_eq!(player.elapsed(), 250.0);
+
+    player.play("flash").unwrap();
+    assert_eq!(player.elapsed(), 0.0);
+    assert!(player.play("spin").is_err());
+    assert_eq!(player.current(), Some("flash"));
+  }
+
+  #[test]
+  fn finishing() {
+    let mut player = player();
+    player.play("flash").unwrap();
+
+    for _ in 0..9 {
+      player.advance(10.0);
+    }
+
+    assert!(!player.is_finished());
+
+    player.advance(10.0);
+    assert!(player.is_finished());
+    assert_eq!(player.sprite().unwrap().uv().left(), 0.5);
+
+    player.restart();
+    assert!(!player.is_finished());
+    assert_eq!(player.sprite().unwrap().uv().left(), 0.0);
+  }
+
+  #[test]
+  fn long_running() {
+    let mut player = player();
+    player.add("spin", Animation::from_sheet(Sprite::full(64.0, 16.0), 4, 1, 100.0, AnimationMode::Loop));
+
+    player.play("spin").unwrap();
+
+    for _ in 0..1_000_001 {
+      player.advance(1_000.0);
+    }
+
+    assert_eq!(player.elapsed(), 200.0);
+    assert_eq!(player.sprite().unwrap().uv().left(), 0.5);
+
+    player.play("pulse").unwrap();
+
+    for _ in 0..1_000_000 {
+      player.advance(650.0);
+    }
+
+    assert_eq!(player.elapsed(), 200.0);
+    assert_eq!(player.sprite().unwrap().uv().left(), 0.5);
+
+    player.play("flash").unwrap();
+    player.advance(f32::MAX);
+
+    assert_eq!(player.elapsed(), 100.0);
+    assert!(player.is_finished());
+  }
+}
\ No newline at end of file
@@ -0,0 +1,143 @@
+use crate::prelude::{Sprite, TextAlignment, TextStyle, Vector2};
+
+pub struct BitmapFont {
+  glyphs: Vec<Sprite>,
+  first_character: char,
+  glyph_width: f32,
+  glyph_height: f32
+}
+
+impl BitmapFont {
+  pub fn grid(sheet: Sprite, columns: u32, rows: u32, first_character: char) -> Self {
+    Self {
+      glyphs: sheet.split_grid(columns, rows),
+      first_character,
+      glyph_width: sheet.width() / columns as f32,
+      glyph_height: sheet.height() / rows as f32
+    }
+  }
+
+  pub fn glyph_width(&self) -> f32 {
+    self.glyph_width
+  }
+
+  pub fn glyph_height(&self) -> f32 {
+    self.glyph_height
+  }
+
+  pub fn glyph(&self, character: char) -> Option<Sprite> {
+    let index = (character as u32).checked_sub(self.first_character as u32)?;
+    self.glyphs.get(index as usize).copied()
+  }
+
+  pub fn measure(&self, text: &str, scale: f32) -> Vector2 {
+    let longest_line = text.lines().map(|line| line.chars().count()).max().unwrap_or(0);
+    let line_count = text.lines().count();
+
+    Vector2::new(
+      longest_line as f32 * self.glyph_width * scale,
+      line_count as f32 * self.glyph_height * scale
+    )
+  }
+
+  pub fn layout(&self, text: &str, position: Vector2, style: &TextStyle) -> Vec<(Sprite, Vector2)> {
+    let advance = self.glyph_width * style.scale;
+    let line_height = self.glyph_height * style.scale;
+    let mut glyphs = Vec::with_capacity(text.len());
+
+    for (line_index, line) in text.lines().enumerate() {
+      let line_width = line.chars().count() as f32 * advance;
+
+      let line_start = match style.alignment {
+        TextAlignment::Left => position.x,
+        TextAlignment::Center => position.x - (line_width / 2.0),
+        TextAlignment::Right => position.x - line_width
+      };
+
+      let center_y = position.y + (line_index as f32 * line_height) + (line_height / 2.0);
+
+      for (character_index, character) in line.chars().enumerate() {
+        if character.is_whitespace() {
+          continue;
+        }
+
+        let Some(glyph) = self.glyph(character).or_else(|| self.glyph('?')) else {
+          continue;
+        };
+
+        let center_x = line_start + (character_index as f32 * advance) + (advance / 2.0);
+
+        glyphs.push((
+          Sprite::new(glyph.uv(), glyph.width() * style.scale, glyph.height() * style.scale),
+          Vector2::new(center_x, center_y)
+        ));
+      }
+    }
+
+    glyphs
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::prelude::Color;
+
+  fn font() -> BitmapFont {
+    BitmapFont::grid(Sprite::full(128.0, 48.0), 16, 6, ' ')
+  }
+
+  #[test]
+  fn glyph_lookup() {
+    let font = font();
+
+    assert_eq!(font.glyph_width(), 8.0);
+    assert_eq!(font.glyph_height(), 8.0);
+
+    let zero = font.glyph('0').unwrap();
+    assert_eq!(zero.uv().left(), 0.0);
+    assert_eq!(zero.uv().top(), 1.0 / 6.0);
+    assert_eq!(zero.width(), 8.0);
+
+    assert!(font.glyph('\t').is_none());
+    assert!(font.glyph('é').is_none());
+  }
+
+  #[test]
+  fn measuring() {
+    let font = font();
+
+    assert_eq!(font.measure("12", 2.0), Vector2::new(32.0, 16.0));
+    assert_eq!(font.measure("WINNER\nA", 1.0), Vector2::new(48.0, 16.0));
+    assert_eq!(font.measure("", 1.0), Vector2::ZERO);
+  }
+
+  #[test]
+  fn alignment() {
+    let font = font();
+    let position = Vector2::new(100.0, 20.0);
+
+    let left = font.layout("AB", position, &TextStyle::new(TextAlignment::Left, 1.0, Color::WHITE));
+    let center = font.layout("AB", position, &TextStyle::new(TextAlignment::Center, 1.0, Color::WHITE));
+    let right = font.layout("AB", position, &TextStyle::new(TextAlignment::Right, 2.0, Color::WHITE));
+
+    assert_eq!(left[0].1, Vector2::new(104.0, 24.0));
+    assert_eq!(left[1].1, Vector2::new(112.0, 24.0));
+    assert_eq!(center[0].1, Vector2::new(96.0, 24.0));
+    assert_eq!(right[1].1, Vector2::new(92.0, 28.0));
+    assert_eq!(right[1].0.width(), 16.0);
+    assert_eq!(left[1].0.uv(), font.glyph('B').unwrap().uv());
+  }
+
+  #[test]
+  fn whitespace_and_lines() {
+    let font = font();
+
+    let glyphs = font.layout("A B\nC\u{1F600}", Vector2::ZERO, &TextStyle::default());
+
+    assert_eq!(glyphs.len(), 4);
+    assert_eq!(glyphs[1].1, Vector2::new(20.0, 4.0));
+    assert_eq!(glyphs[2].1, Vector2::new(4.0, 12.0));
+    assert_eq!(glyphs[3].0.uv(), font.glyph('?').unwrap().uv());
+  }
+}
\ No newline at end of file
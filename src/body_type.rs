@@ -0,0 +1,5 @@
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum BodyType {
+  Static,
+  Dynamic
+}
\ No newline at end of file
@@ -0,0 +1,146 @@
+use crate::prelude::{Collider, ColliderHandle, ColliderWorld, Rect};
+
+struct Interval<'a> {
+  handle: ColliderHandle,
+  collider: &'a Collider,
+  rect: Rect
+}
+
+pub fn find_candidate_pairs(world: &ColliderWorld) -> Vec<(ColliderHandle, ColliderHandle)> {
+  let mut intervals: Vec<Interval> = world
+    .iter()
+    .map(|(handle, collider)| Interval {
+      handle,
+      collider,
+      rect: collider.rect()
+    })
+    .collect();
+
+  intervals.sort_by(|a, b| a.rect.left().total_cmp(&b.rect.left()));
+
+  let mut pairs = vec![];
+  let mut active: Vec<&Interval> = vec![];
+
+  for interval in &intervals {
+    active.retain(|active_interval| active_interval.rect.right() > interval.rect.left());
+
+    for active_interval in &active {
+      if let Some((primary, secondary)) = order_pair(active_interval, interval)
+      && overlaps_vertically(&primary.rect, &secondary.rect) {
+        pairs.push((primary.handle, secondary.handle));
+      }
+    }
+
+    active.push(interval);
+  }
+
+  pairs.sort();
+  pairs
+}
+
+fn order_pair<'a>(first: &'a Interval<'a>, second: &'a Interval<'a>) -> Option<(&'a Interval<'a>, &'a Interval<'a>)> {
+  if !first.collider.can_collide_with(second.collider) {
+    return None;
+  }
+
+  match (first.collider.is_static(), second.collider.is_static()) {
+    (true, true) => None,
+    (true, false) => Some((second, first)),
+    (false, true) => Some((first, second)),
+    (false, false) => {
+      if (first.collider.layer().bits(), first.handle) < (second.collider.layer().bits(), second.handle) {
+        Some((first, second))
+      } else {
+        Some((second, first))
+      }
+    }
+  }
+}
+
+fn overlaps_vertically(primary_rect: &Rect, secondary_rect: &Rect) -> bool {
+  primary_rect.bottom() > secondary_rect.top() && secondary_rect.bottom() > primary_rect.top()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::prelude::CollisionLayer;
+
+  #[test]
+  fn no_pairs() {
+    let mut world = ColliderWorld::new();
+    world.insert(Collider::new(0.0, 0.0, 16.0, 16.0));
+    world.insert(Collider::new(32.0, 0.0, 16.0, 16.0));
+    world.insert(Collider::new(0.0, 32.0, 16.0, 16.0));
+
+    assert!(find_candidate_pairs(&world).is_empty());
+  }
+
+  #[test]
+  fn overlapping_pairs_reported_once() {
+    let mut world = ColliderWorld::new();
+    let center = world.insert(Collider::new(0.0, 0.0, 32.0, 32.0));
+    let right = world.insert(Collider::new(16.0, 0.0, 32.0, 32.0));
+    let left = world.insert(Collider::new(-16.0, 0.0, 32.0, 32.0));
+
+    let pairs = find_candidate_pairs(&world);
+
+    assert_eq!(pairs, vec![(center, right), (center, left)]);
+  }
+
+  #[test]
+  fn static_pairs_skipped() {
+    let mut world = ColliderWorld::new();
+    world.insert(Collider::new_static(0.0, 0.0, 32.0, 32.0));
+    world.insert(Collider::new_static(16.0, 0.0, 32.0, 32.0));
+
+    assert!(find_candidate_pairs(&world).is_empty());
+  }
+
+  #[test]
+  fn dynamic_collider_is_primary() {
+    let mut world = ColliderWorld::new();
+    let static_handle = world.insert(Collider::new_static(0.0, 0.0, 32.0, 32.0));
+    let dynamic_handle = world.insert(Collider::new(16.0, 0.0, 32.0, 32.0));
+
+    let pairs = find_candidate_pairs(&world);
+
+    assert_eq!(pairs, vec![(dynamic_handle, static_handle)]);
+  }
+
+  #[test]
+  fn lower_layer_is_primary() {
+    let first_layer = CollisionLayer::from_bits(0b01);
+    let second_layer = CollisionLayer::from_bits(0b10);
+
+    let mut world = ColliderWorld::new();
+    let second_handle = world.insert(Collider::new(0.0, 0.0, 32.0, 32.0).with_layer(second_layer, first_layer));
+    let first_handle = world.insert(Collider::new(16.0, 0.0, 32.0, 32.0).with_layer(first_layer, second_layer));
+
+    let pairs = find_candidate_pairs(&world);
+
+    assert_eq!(pairs, vec![(first_handle, second_handle)]);
+  }
+
+  #[test]
+  fn masked_pairs_skipped() {
+    let first_layer = CollisionLayer::from_bits(0b01);
+    let second_layer = CollisionLayer::from_bits(0b10);
+
+    let mut world = ColliderWorld::new();
+    world.insert(Collider::new(0.0, 0.0, 32.0, 32.0).with_layer(first_layer, first_layer));
+    world.insert(Collider::new(16.0, 0.0, 32.0, 32.0).with_layer(second_layer, first_layer | second_layer));
+
+    assert!(find_candidate_pairs(&world).is_empty());
+  }
+
+  #[test]
+  fn removed_colliders_skipped() {
+    let mut world = ColliderWorld::new();
+    world.insert(Collider::new(0.0, 0.0, 32.0, 32.0));
+    let removed_handle = world.insert(Collider::new(16.0, 0.0, 32.0, 32.0));
+    world.remove(removed_handle);
+
+    assert!(find_candidate_pairs(&world).is_empty());
+  }
+}
\ No newline at end of file
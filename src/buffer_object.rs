@@ -3,7 +3,7 @@ pub struct BufferObject {
 }
 
 impl BufferObject {
-  pub fn vertex_array(vertex_data: Vec<f32>) -> Self {
+  pub fn dynamic_vertex_array(capacity: usize) -> Self {
     let mut id: gl::types::GLuint = 0;
 
     unsafe {
@@ -12,9 +12,9 @@ impl BufferObject {
 
       gl::BufferData(
         gl::ARRAY_BUFFER,
-        (vertex_data.len() * std::mem::size_of::<f32>()) as gl::types::GLsizeiptr,
-        vertex_data.as_ptr() as *const gl::types::GLvoid,
-        gl::STATIC_DRAW
+        (capacity * std::mem::size_of::<f32>()) as gl::types::GLsizeiptr,
+        std::ptr::null(),
+        gl::DYNAMIC_DRAW
       );
 
       gl::BindBuffer(gl::ARRAY_BUFFER, 0);
@@ -23,6 +23,21 @@ impl BufferObject {
     Self { id }
   }
 
+  pub fn update_vertex_data(&self, vertex_data: &[f32]) {
+    unsafe {
+      gl::BindBuffer(gl::ARRAY_BUFFER, self.id);
+
+      gl::BufferSubData(
+        gl::ARRAY_BUFFER,
+        0,
+        std::mem::size_of_val(vertex_data) as gl::types::GLsizeiptr,
+        vertex_data.as_ptr() as *const gl::types::GLvoid
+      );
+
+      gl::BindBuffer(gl::ARRAY_BUFFER, 0);
+    }
+  }
+
   pub fn element_array(element_data: Vec<i32>) -> Self {
     let mut id: gl::types::GLuint = 0;
 
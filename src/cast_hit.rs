@@ -0,0 +1,55 @@
+use crate::prelude::{ColliderHandle, Vector2};
+
+#[derive(Clone, Copy)]
+pub struct CastHit {
+  handle: ColliderHandle,
+  distance: f32,
+  point: Vector2,
+  normal: Vector2
+}
+
+impl CastHit {
+  pub fn new(handle: ColliderHandle, distance: f32, point: Vector2, normal: Vector2) -> Self {
+    Self {
+      handle,
+      distance,
+      point,
+      normal
+    }
+  }
+
+  pub fn handle(&self) -> ColliderHandle {
+    self.handle
+  }
+
+  pub fn distance(&self) -> f32 {
+    self.distance
+  }
+
+  pub fn point(&self) -> Vector2 {
+    self.point
+  }
+
+  pub fn normal(&self) -> Vector2 {
+    self.normal
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn constructor() {
+    let handle = ColliderHandle::new(3, 1);
+
+    let hit = CastHit::new(handle, 12.0, Vector2::new(4.0, 8.0), Vector2::new(0.0, -1.0));
+
+    assert_eq!(hit.handle, handle);
+    assert_eq!(hit.distance, 12.0);
+    assert_eq!(hit.point.x, 4.0);
+    assert_eq!(hit.point.y, 8.0);
+    assert_eq!(hit.normal.x, 0.0);
+    assert_eq!(hit.normal.y, -1.0);
+  }
+}
\ No newline at end of file
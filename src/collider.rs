@@ -1,39 +1,140 @@
-use crate::{math::Vector2, prelude::Location};
+use crate::prelude::{BodyType, ColliderShape, CollisionLayer, Rect, Transform, Vector2};
 
 pub struct Collider {
-  location: Location,
+  transform: Transform,
   width: f32,
-  height: f32
+  height: f32,
+  shape: ColliderShape,
+  body_type: BodyType,
+  layer: CollisionLayer,
+  mask: CollisionLayer
 }
 
 impl Collider {
   pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
     Self {
-      location: Location::new(x, y),
+      transform: Transform::new(x, y),
       width,
-      height
+      height,
+      shape: ColliderShape::Rectangle,
+      body_type: BodyType::Dynamic,
+      layer: CollisionLayer::ALL,
+      mask: CollisionLayer::ALL
     }
   }
 
-  pub fn location(&self) -> &Location {
-    &self.location
+  pub fn new_static(x: f32, y: f32, width: f32, height: f32) -> Self {
+    Self {
+      transform: Transform::new(x, y),
+      width,
+      height,
+      shape: ColliderShape::Rectangle,
+      body_type: BodyType::Static,
+      layer: CollisionLayer::ALL,
+      mask: CollisionLayer::ALL
+    }
+  }
+
+  pub fn new_circle(x: f32, y: f32, radius: f32) -> Self {
+    Self {
+      transform: Transform::new(x, y),
+      width: radius * 2.0,
+      height: radius * 2.0,
+      shape: ColliderShape::Circle { radius },
+      body_type: BodyType::Dynamic,
+      layer: CollisionLayer::ALL,
+      mask: CollisionLayer::ALL
+    }
+  }
+
+  pub fn new_static_circle(x: f32, y: f32, radius: f32) -> Self {
+    Self {
+      transform: Transform::new(x, y),
+      width: radius * 2.0,
+      height: radius * 2.0,
+      shape: ColliderShape::Circle { radius },
+      body_type: BodyType::Static,
+      layer: CollisionLayer::ALL,
+      mask: CollisionLayer::ALL
+    }
+  }
+
+  pub fn with_layer(mut self, layer: CollisionLayer, mask: CollisionLayer) -> Self {
+    self.layer = layer;
+    self.mask = mask;
+    self
+  }
+
+  pub fn transform(&self) -> &Transform {
+    &self.transform
+  }
+
+  pub fn position(&self) -> Vector2 {
+    self.transform.world_position()
   }
 
   pub fn width(&self) -> f32 {
-    self.width
+    let (width_axis, height_axis) = self.world_axes();
+
+    width_axis.x.abs() + height_axis.x.abs()
   }
 
   pub fn height(&self) -> f32 {
-    self.height
+    let (width_axis, height_axis) = self.world_axes();
+
+    width_axis.y.abs() + height_axis.y.abs()
+  }
+
+  pub fn rect(&self) -> Rect {
+    Rect::from_center(self.position(), self.width(), self.height())
+  }
+
+  fn world_axes(&self) -> (Vector2, Vector2) {
+    let affine = self.transform.affine();
+
+    (affine.transform_vector(Vector2::new(self.width, 0.0)), affine.transform_vector(Vector2::new(0.0, self.height)))
+  }
+
+  pub fn shape(&self) -> ColliderShape {
+    match self.shape {
+      ColliderShape::Rectangle => ColliderShape::Rectangle,
+      ColliderShape::Circle { radius } => {
+        let scale = self.transform.world_scale();
+        ColliderShape::Circle { radius: radius * scale.x.abs().max(scale.y.abs()) }
+      }
+    }
+  }
+
+  pub fn body_type(&self) -> BodyType {
+    self.body_type
+  }
+
+  pub fn is_static(&self) -> bool {
+    self.body_type == BodyType::Static
+  }
+
+  pub fn layer(&self) -> CollisionLayer {
+    self.layer
+  }
+
+  pub fn mask(&self) -> CollisionLayer {
+    self.mask
+  }
+
+  pub fn can_collide_with(&self, other: &Collider) -> bool {
+    self.mask.intersects(other.layer) && other.mask.intersects(self.layer)
   }
 
-  pub fn set_location(&mut self, location: &Location) {
-    self.location.set(Vector2::new(location.x(), location.y()));
+  pub fn set_transform(&mut self, transform: &Transform) {
+    self.transform = transform.clone();
   }
 }
 
 #[cfg(test)]
 mod tests {
+  use std::cell::RefCell;
+  use std::rc::Rc;
+
   use super::*;
 
   #[test]
@@ -45,14 +146,39 @@ mod tests {
 
     let collider = Collider::new(location_x, location_y, width, height);
 
-    assert_eq!(collider.location.x(), 32.0);
-    assert_eq!(collider.location.y(), 32.0);
+    assert_eq!(collider.transform.x(), 32.0);
+    assert_eq!(collider.transform.y(), 32.0);
     assert_eq!(collider.width, 64.0);
     assert_eq!(collider.height, 32.0);
+    assert_eq!(collider.body_type, BodyType::Dynamic);
+  }
+
+  #[test]
+  fn static_constructor() {
+    let collider = Collider::new_static(16.0, 8.0, 4.0, 2.0);
+
+    assert_eq!(collider.transform.x(), 16.0);
+    assert_eq!(collider.transform.y(), 8.0);
+    assert_eq!(collider.width, 4.0);
+    assert_eq!(collider.height, 2.0);
+    assert!(collider.is_static());
+  }
+
+  #[test]
+  fn circle_constructor() {
+    let collider = Collider::new_circle(16.0, 8.0, 4.0);
+
+    assert_eq!(collider.transform.x(), 16.0);
+    assert_eq!(collider.transform.y(), 8.0);
+    assert_eq!(collider.width, 8.0);
+    assert_eq!(collider.height, 8.0);
+    assert_eq!(collider.shape(), ColliderShape::Circle { radius: 4.0 });
+    assert!(!collider.is_static());
+    assert!(Collider::new_static_circle(0.0, 0.0, 4.0).is_static());
   }
 
   #[test]
-  fn setting_location() {
+  fn setting_transform() {
     let location_x = 0.0;
     let location_y = 0.0;
     let width = 4.0;
@@ -61,12 +187,87 @@ mod tests {
     let new_location_x = 2.0;
     let new_location_y = 2.0;
 
-    let new_location = Location::new(new_location_x, new_location_y);
+    let new_transform = Transform::new(new_location_x, new_location_y);
 
     let mut collider = Collider::new(location_x, location_y, width, height);
-    collider.set_location(&new_location);
+    collider.set_transform(&new_transform);
+
+    assert_eq!(collider.transform.x(), new_location_x);
+    assert_eq!(collider.transform.y(), new_location_y);
+  }
+
+  #[test]
+  fn layers() {
+    let first_layer = CollisionLayer::from_bits(0b01);
+    let second_layer = CollisionLayer::from_bits(0b10);
+
+    let collider = Collider::new(0.0, 0.0, 4.0, 4.0).with_layer(first_layer, second_layer);
+
+    assert_eq!(collider.layer(), first_layer);
+    assert_eq!(collider.mask(), second_layer);
+  }
+
+  #[test]
+  fn layer_filtering() {
+    let ball_layer = CollisionLayer::from_bits(0b001);
+    let paddle_layer = CollisionLayer::from_bits(0b010);
+    let power_up_layer = CollisionLayer::from_bits(0b100);
+
+    let ball = Collider::new(0.0, 0.0, 4.0, 4.0).with_layer(ball_layer, paddle_layer | power_up_layer);
+    let paddle = Collider::new(0.0, 0.0, 4.0, 4.0).with_layer(paddle_layer, ball_layer);
+    let power_up = Collider::new(0.0, 0.0, 4.0, 4.0).with_layer(power_up_layer, ball_layer);
+    let unfiltered = Collider::new(0.0, 0.0, 4.0, 4.0);
+    let ignored = Collider::new(0.0, 0.0, 4.0, 4.0).with_layer(paddle_layer, CollisionLayer::NONE);
+
+    assert!(ball.can_collide_with(&paddle));
+    assert!(ball.can_collide_with(&power_up));
+    assert!(!paddle.can_collide_with(&power_up));
+    assert!(unfiltered.can_collide_with(&ball));
+    assert!(!unfiltered.can_collide_with(&ignored));
+  }
+
+  #[test]
+  fn transform_sizing() {
+    let mut transform = Transform::new(0.0, 0.0);
+    transform.set_scale(Vector2::new(1.0, 1.5));
+
+    let mut paddle = Collider::new(0.0, 0.0, 16.0, 128.0);
+    paddle.set_transform(&transform);
+
+    assert_eq!(paddle.width(), 16.0);
+    assert_eq!(paddle.height(), 192.0);
+    assert_eq!(paddle.rect(), Rect::from_center(Vector2::new(0.0, 0.0), 16.0, 192.0));
+
+    transform.set_rotation(std::f32::consts::FRAC_PI_2);
+    paddle.set_transform(&transform);
+
+    assert!((paddle.width() - 192.0).abs() < 0.001);
+    assert!((paddle.height() - 16.0).abs() < 0.001);
+
+    let mut ball = Collider::new_circle(0.0, 0.0, 8.0);
+    transform.set_scale(Vector2::new(2.0, 1.0));
+    ball.set_transform(&transform);
+
+    assert_eq!(ball.shape(), ColliderShape::Circle { radius: 16.0 });
+  }
+
+  #[test]
+  fn parented_transform() {
+    let parent = Rc::new(RefCell::new(Transform::new(0.0, 0.0)));
+    parent.borrow_mut().set_scale(Vector2::new(2.0, 1.0));
+
+    let mut transform = Transform::new(0.0, 0.0);
+    transform.set_rotation(std::f32::consts::FRAC_PI_2);
+    transform.set_parent(Some(Rc::clone(&parent))).unwrap();
+
+    let mut paddle = Collider::new(0.0, 0.0, 16.0, 128.0);
+    paddle.set_transform(&transform);
+
+    assert!((paddle.width() - 256.0).abs() < 0.001);
+    assert!((paddle.height() - 16.0).abs() < 0.001);
+
+    parent.borrow_mut().translate(Vector2::new(50.0, 0.0));
 
-    assert_eq!(collider.location.x(), new_location_x);
-    assert_eq!(collider.location.y(), new_location_y);
+    assert_eq!(paddle.position(), Vector2::new(50.0, 0.0));
   }
 }
\ No newline at end of file
@@ -0,0 +1,42 @@
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub struct ColliderHandle {
+  index: usize,
+  generation: u32
+}
+
+impl ColliderHandle {
+  pub fn new(index: usize, generation: u32) -> Self {
+    Self {
+      index,
+      generation
+    }
+  }
+
+  pub fn index(&self) -> usize {
+    self.index
+  }
+
+  pub fn generation(&self) -> u32 {
+    self.generation
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn constructor() {
+    let handle = ColliderHandle::new(4, 2);
+
+    assert_eq!(handle.index(), 4);
+    assert_eq!(handle.generation(), 2);
+  }
+
+  #[test]
+  fn equality() {
+    assert_eq!(ColliderHandle::new(1, 0), ColliderHandle::new(1, 0));
+    assert_ne!(ColliderHandle::new(1, 0), ColliderHandle::new(1, 1));
+    assert_ne!(ColliderHandle::new(1, 0), ColliderHandle::new(2, 0));
+  }
+}
\ No newline at end of file
@@ -0,0 +1,5 @@
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum ColliderShape {
+  Rectangle,
+  Circle { radius: f32 }
+}
\ No newline at end of file
@@ -0,0 +1,193 @@
+use crate::prelude::{Collider, ColliderHandle};
+
+struct ColliderSlot {
+  generation: u32,
+  collider: Option<Collider>
+}
+
+#[derive(Default)]
+pub struct ColliderWorld {
+  slots: Vec<ColliderSlot>,
+  free_indices: Vec<usize>,
+  len: usize
+}
+
+impl ColliderWorld {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  pub fn insert(&mut self, collider: Collider) -> ColliderHandle {
+    self.len += 1;
+
+    if let Some(index) = self.free_indices.pop() {
+      let slot = &mut self.slots[index];
+      slot.collider = Some(collider);
+
+      return ColliderHandle::new(index, slot.generation);
+    }
+
+    self.slots.push(ColliderSlot {
+      generation: 0,
+      collider: Some(collider)
+    });
+
+    ColliderHandle::new(self.slots.len() - 1, 0)
+  }
+
+  pub fn remove(&mut self, handle: ColliderHandle) -> Option<Collider> {
+    let slot = self.slots.get_mut(handle.index())?;
+
+    if slot.generation != handle.generation() {
+      return None;
+    }
+
+    let collider = slot.collider.take()?;
+    slot.generation = slot.generation.wrapping_add(1);
+
+    self.free_indices.push(handle.index());
+    self.len -= 1;
+
+    Some(collider)
+  }
+
+  pub fn contains(&self, handle: ColliderHandle) -> bool {
+    self.get(handle).is_some()
+  }
+
+  pub fn get(&self, handle: ColliderHandle) -> Option<&Collider> {
+    let slot = self.slots.get(handle.index())?;
+
+    if slot.generation != handle.generation() {
+      return None;
+    }
+
+    slot.collider.as_ref()
+  }
+
+  pub fn get_mut(&mut self, handle: ColliderHandle) -> Option<&mut Collider> {
+    let slot = self.slots.get_mut(handle.index())?;
+
+    if slot.generation != handle.generation() {
+      return None;
+    }
+
+    slot.collider.as_mut()
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = (ColliderHandle, &Collider)> {
+    self.slots
+      .iter()
+      .enumerate()
+      .filter_map(|(index, slot)| {
+        slot.collider
+          .as_ref()
+          .map(|collider| (ColliderHandle::new(index, slot.generation), collider))
+      })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::prelude::Transform;
+
+  #[test]
+  fn constructor() {
+    let world = ColliderWorld::new();
+
+    assert_eq!(world.len(), 0);
+    assert!(world.is_empty());
+  }
+
+  #[test]
+  fn insertion() {
+    let mut world = ColliderWorld::new();
+
+    let first_handle = world.insert(Collider::new(1.0, 2.0, 4.0, 8.0));
+    let second_handle = world.insert(Collider::new(3.0, 4.0, 4.0, 8.0));
+
+    assert_eq!(world.len(), 2);
+    assert_ne!(first_handle, second_handle);
+
+    match world.get(second_handle) {
+      Some(collider) => {
+        assert_eq!(collider.position().x, 3.0);
+        assert_eq!(collider.position().y, 4.0);
+      },
+
+      None => panic!("Collider Not Found")
+    }
+  }
+
+  #[test]
+  fn removal() {
+    let mut world = ColliderWorld::new();
+
+    let first_handle = world.insert(Collider::new(1.0, 2.0, 4.0, 8.0));
+    let second_handle = world.insert(Collider::new(3.0, 4.0, 4.0, 8.0));
+
+    let removed = world.remove(first_handle);
+
+    assert!(removed.is_some());
+    assert_eq!(world.len(), 1);
+    assert!(!world.contains(first_handle));
+    assert!(world.contains(second_handle));
+    assert!(world.remove(first_handle).is_none());
+  }
+
+  #[test]
+  fn stale_handle_after_reuse() {
+    let mut world = ColliderWorld::new();
+
+    let stale_handle = world.insert(Collider::new(1.0, 2.0, 4.0, 8.0));
+    world.remove(stale_handle);
+    let new_handle = world.insert(Collider::new(3.0, 4.0, 4.0, 8.0));
+
+    assert_eq!(new_handle.index(), stale_handle.index());
+    assert_ne!(new_handle.generation(), stale_handle.generation());
+    assert!(world.get(stale_handle).is_none());
+    assert!(world.get(new_handle).is_some());
+  }
+
+  #[test]
+  fn mutation() {
+    let mut world = ColliderWorld::new();
+    let handle = world.insert(Collider::new(0.0, 0.0, 4.0, 8.0));
+
+    if let Some(collider) = world.get_mut(handle) {
+      collider.set_transform(&Transform::new(8.0, 16.0));
+    }
+
+    match world.get(handle) {
+      Some(collider) => {
+        assert_eq!(collider.position().x, 8.0);
+        assert_eq!(collider.position().y, 16.0);
+      },
+
+      None => panic!("Collider Not Found")
+    }
+  }
+
+  #[test]
+  fn iteration_skips_removed() {
+    let mut world = ColliderWorld::new();
+
+    let first_handle = world.insert(Collider::new(1.0, 0.0, 4.0, 8.0));
+    let second_handle = world.insert(Collider::new(2.0, 0.0, 4.0, 8.0));
+    let third_handle = world.insert(Collider::new(3.0, 0.0, 4.0, 8.0));
+    world.remove(second_handle);
+
+    let handles: Vec<ColliderHandle> = world.iter().map(|(handle, _)| handle).collect();
+
+    assert_eq!(handles, vec![first_handle, third_handle]);
+  }
+}
\ No newline at end of file
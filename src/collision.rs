@@ -1,28 +1,55 @@
-use crate::prelude::CollisionDirection;
+use crate::prelude::{ColliderHandle, CollisionDirection, CollisionLayer, Vector2};
 
+#[derive(Clone, Copy)]
 pub struct Collision {
-  primary_index: usize,
-  secondary_index: usize,
+  primary_handle: ColliderHandle,
+  secondary_handle: ColliderHandle,
+  primary_layer: CollisionLayer,
+  secondary_layer: CollisionLayer,
+  normal: Vector2,
   entry_direction: CollisionDirection,
   penetration_depth: f32
 }
 
 impl Collision {
-  pub fn new(primary_index: usize, secondary_index: usize, entry_direction: CollisionDirection, penetration_depth: f32) -> Self {
+  pub fn new(
+    primary_handle: ColliderHandle,
+    secondary_handle: ColliderHandle,
+    primary_layer: CollisionLayer,
+    secondary_layer: CollisionLayer,
+    normal: Vector2,
+    entry_direction: CollisionDirection,
+    penetration_depth: f32
+  ) -> Self {
     Self {
-      primary_index,
-      secondary_index,
+      primary_handle,
+      secondary_handle,
+      primary_layer,
+      secondary_layer,
+      normal,
       entry_direction,
       penetration_depth
     }
   }
 
-  pub fn primary_index(&self) -> usize {
-    self.primary_index
+  pub fn primary_handle(&self) -> ColliderHandle {
+    self.primary_handle
   }
 
-  pub fn secondary_index(&self) -> usize {
-    self.secondary_index
+  pub fn secondary_handle(&self) -> ColliderHandle {
+    self.secondary_handle
+  }
+
+  pub fn primary_layer(&self) -> CollisionLayer {
+    self.primary_layer
+  }
+
+  pub fn secondary_layer(&self) -> CollisionLayer {
+    self.secondary_layer
+  }
+
+  pub fn normal(&self) -> Vector2 {
+    self.normal
   }
 
   pub fn entry_direction(&self) -> CollisionDirection {
@@ -37,15 +64,26 @@ impl Collision {
 #[cfg(test)]
 mod tests {
   use super::Collision;
-  use crate::prelude::CollisionDirection;
+  use crate::prelude::{ColliderHandle, CollisionDirection, CollisionLayer};
 
   #[test]
   fn constructor() {
     let direction = CollisionDirection::Left;
     let depth = 4.0;
+    let primary_layer = CollisionLayer::from_bits(0b01);
+    let secondary_layer = CollisionLayer::from_bits(0b10);
+
+    let primary_handle = ColliderHandle::new(0, 0);
+    let secondary_handle = ColliderHandle::new(1, 0);
 
-    let collision = Collision::new(0, 0, direction, depth);
+    let collision = Collision::new(primary_handle, secondary_handle, primary_layer, secondary_layer, direction.normal(), direction, depth);
 
+    assert_eq!(collision.primary_handle, primary_handle);
+    assert_eq!(collision.secondary_handle, secondary_handle);
+    assert_eq!(collision.primary_layer, primary_layer);
+    assert_eq!(collision.secondary_layer, secondary_layer);
+    assert_eq!(collision.normal.x, -1.0);
+    assert_eq!(collision.normal.y, 0.0);
     assert_eq!(collision.entry_direction, direction);
     assert_eq!(collision.penetration_depth, depth);
   }
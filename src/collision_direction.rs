@@ -1,7 +1,51 @@
+use crate::prelude::Vector2;
+
 #[derive(PartialEq, Debug, Clone, Copy)]
 pub enum CollisionDirection {
   Left,
   Right,
   Top,
   Bottom
+}
+
+impl CollisionDirection {
+  pub fn normal(&self) -> Vector2 {
+    match self {
+      CollisionDirection::Left => Vector2::new(-1.0, 0.0),
+      CollisionDirection::Right => Vector2::new(1.0, 0.0),
+      CollisionDirection::Top => Vector2::new(0.0, -1.0),
+      CollisionDirection::Bottom => Vector2::new(0.0, 1.0)
+    }
+  }
+
+  pub fn from_normal(normal: Vector2) -> Self {
+    if normal.x.abs() >= normal.y.abs() {
+      if normal.x < 0.0 { CollisionDirection::Left } else { CollisionDirection::Right }
+    } else if normal.y < 0.0 {
+      CollisionDirection::Top
+    } else {
+      CollisionDirection::Bottom
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn normals() {
+    assert_eq!(CollisionDirection::Left.normal().x, -1.0);
+    assert_eq!(CollisionDirection::Right.normal().x, 1.0);
+    assert_eq!(CollisionDirection::Top.normal().y, -1.0);
+    assert_eq!(CollisionDirection::Bottom.normal().y, 1.0);
+  }
+
+  #[test]
+  fn from_normal() {
+    assert_eq!(CollisionDirection::from_normal(Vector2::new(-0.8, 0.6)), CollisionDirection::Left);
+    assert_eq!(CollisionDirection::from_normal(Vector2::new(0.8, -0.6)), CollisionDirection::Right);
+    assert_eq!(CollisionDirection::from_normal(Vector2::new(0.6, -0.8)), CollisionDirection::Top);
+    assert_eq!(CollisionDirection::from_normal(Vector2::new(-0.6, 0.8)), CollisionDirection::Bottom);
+  }
 }
\ No newline at end of file
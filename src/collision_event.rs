@@ -0,0 +1,60 @@
+use crate::prelude::{ColliderHandle, CollisionPhase};
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct CollisionEvent {
+  primary_handle: ColliderHandle,
+  secondary_handle: ColliderHandle,
+  phase: CollisionPhase
+}
+
+impl CollisionEvent {
+  pub fn new(primary_handle: ColliderHandle, secondary_handle: ColliderHandle, phase: CollisionPhase) -> Self {
+    Self {
+      primary_handle,
+      secondary_handle,
+      phase
+    }
+  }
+
+  pub fn primary_handle(&self) -> ColliderHandle {
+    self.primary_handle
+  }
+
+  pub fn secondary_handle(&self) -> ColliderHandle {
+    self.secondary_handle
+  }
+
+  pub fn phase(&self) -> CollisionPhase {
+    self.phase
+  }
+
+  pub fn involves(&self, handle: ColliderHandle) -> bool {
+    self.primary_handle == handle || self.secondary_handle == handle
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn constructor() {
+    let primary_handle = ColliderHandle::new(0, 0);
+    let secondary_handle = ColliderHandle::new(1, 0);
+
+    let event = CollisionEvent::new(primary_handle, secondary_handle, CollisionPhase::Enter);
+
+    assert_eq!(event.primary_handle(), primary_handle);
+    assert_eq!(event.secondary_handle(), secondary_handle);
+    assert_eq!(event.phase(), CollisionPhase::Enter);
+  }
+
+  #[test]
+  fn involvement() {
+    let event = CollisionEvent::new(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), CollisionPhase::Stay);
+
+    assert!(event.involves(ColliderHandle::new(0, 0)));
+    assert!(event.involves(ColliderHandle::new(1, 0)));
+    assert!(!event.involves(ColliderHandle::new(1, 1)));
+  }
+}
\ No newline at end of file
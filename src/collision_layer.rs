@@ -0,0 +1,72 @@
+use std::ops::BitOr;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct CollisionLayer {
+  bits: u32
+}
+
+impl CollisionLayer {
+  pub const NONE: Self = Self::from_bits(0);
+  pub const ALL: Self = Self::from_bits(u32::MAX);
+
+  pub const fn from_bits(bits: u32) -> Self {
+    Self { bits }
+  }
+
+  pub fn bits(&self) -> u32 {
+    self.bits
+  }
+
+  pub fn contains(&self, other: CollisionLayer) -> bool {
+    (self.bits & other.bits) == other.bits
+  }
+
+  pub fn intersects(&self, other: CollisionLayer) -> bool {
+    (self.bits & other.bits) != 0
+  }
+}
+
+impl BitOr for CollisionLayer {
+  type Output = Self;
+
+  fn bitor(self, rhs: Self) -> Self::Output {
+    Self::from_bits(self.bits | rhs.bits)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn constructor() {
+    let layer = CollisionLayer::from_bits(0b0101);
+
+    assert_eq!(layer.bits(), 0b0101);
+  }
+
+  #[test]
+  fn union() {
+    let layer = CollisionLayer::from_bits(0b0001) | CollisionLayer::from_bits(0b0100);
+
+    assert_eq!(layer.bits(), 0b0101);
+  }
+
+  #[test]
+  fn containment() {
+    let layer = CollisionLayer::from_bits(0b0011);
+
+    assert!(layer.contains(CollisionLayer::from_bits(0b0001)));
+    assert!(!layer.contains(CollisionLayer::from_bits(0b0101)));
+    assert!(CollisionLayer::ALL.contains(layer));
+  }
+
+  #[test]
+  fn intersection() {
+    let layer = CollisionLayer::from_bits(0b0011);
+
+    assert!(layer.intersects(CollisionLayer::from_bits(0b0110)));
+    assert!(!layer.intersects(CollisionLayer::from_bits(0b0100)));
+    assert!(!layer.intersects(CollisionLayer::NONE));
+  }
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum CollisionPhase {
+  Enter,
+  Stay,
+  Exit
+}
\ No newline at end of file
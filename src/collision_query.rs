@@ -0,0 +1,251 @@
+use crate::prelude::{
+  CastHit,
+  Collider,
+  ColliderShape,
+  ColliderWorld,
+  CollisionLayer,
+  Vector2
+};
+
+pub fn raycast(world: &ColliderWorld, origin: Vector2, direction: Vector2, max_distance: f32, mask: CollisionLayer) -> Option<CastHit> {
+  cast(world, origin, 0.0, 0.0, direction, max_distance, mask)
+}
+
+pub fn aabb_cast(
+  world: &ColliderWorld,
+  origin: Vector2,
+  width: f32,
+  height: f32,
+  direction: Vector2,
+  max_distance: f32,
+  mask: CollisionLayer
+) -> Option<CastHit> {
+  cast(world, origin, width / 2.0, height / 2.0, direction, max_distance, mask)
+}
+
+fn cast(
+  world: &ColliderWorld,
+  origin: Vector2,
+  half_width: f32,
+  half_height: f32,
+  direction: Vector2,
+  max_distance: f32,
+  mask: CollisionLayer
+) -> Option<CastHit> {
+  let direction = direction.normalized();
+
+  if direction == Vector2::ZERO {
+    return None;
+  }
+
+  let mut closest_hit: Option<CastHit> = None;
+
+  for (handle, collider) in world.iter() {
+    if !mask.intersects(collider.layer()) {
+      continue;
+    }
+
+    let contact = match collider.shape() {
+      ColliderShape::Circle { radius } if half_width == 0.0 && half_height == 0.0 => cast_against_circle(origin, direction, collider, radius),
+      _ => cast_against_box(origin, half_width, half_height, direction, collider)
+    };
+
+    if let Some((distance, normal)) = contact
+    && distance <= max_distance
+    && closest_hit.is_none_or(|hit| distance < hit.distance()) {
+      let point = origin + (direction * distance);
+      closest_hit = Some(CastHit::new(handle, distance, point, normal));
+    }
+  }
+
+  closest_hit
+}
+
+fn cast_against_box(origin: Vector2, half_width: f32, half_height: f32, direction: Vector2, collider: &Collider) -> Option<(f32, Vector2)> {
+  let expanded_half_width = (collider.width() / 2.0) + half_width;
+  let expanded_half_height = (collider.height() / 2.0) + half_height;
+
+  let (entry_x, exit_x) = find_slab_interval(origin.x, direction.x, collider.position().x, expanded_half_width)?;
+  let (entry_y, exit_y) = find_slab_interval(origin.y, direction.y, collider.position().y, expanded_half_height)?;
+
+  let entry = if entry_x > entry_y { entry_x } else { entry_y };
+  let exit = if exit_x < exit_y { exit_x } else { exit_y };
+
+  if entry > exit || entry < 0.0 {
+    return None;
+  }
+
+  let normal = if entry_x > entry_y {
+    Vector2::new(-direction.x.signum(), 0.0)
+  } else {
+    Vector2::new(0.0, -direction.y.signum())
+  };
+
+  Some((entry, normal))
+}
+
+fn find_slab_interval(origin: f32, direction: f32, center: f32, half_extent: f32) -> Option<(f32, f32)> {
+  let near = center - half_extent;
+  let far = center + half_extent;
+
+  if direction == 0.0 {
+    if origin <= near || origin >= far {
+      return None;
+    }
+
+    return Some((f32::NEG_INFINITY, f32::INFINITY));
+  }
+
+  let first = (near - origin) / direction;
+  let second = (far - origin) / direction;
+
+  if first < second {
+    Some((first, second))
+  } else {
+    Some((second, first))
+  }
+}
+
+fn cast_against_circle(origin: Vector2, direction: Vector2, collider: &Collider, radius: f32) -> Option<(f32, Vector2)> {
+  let offset = origin - collider.position();
+
+  let projection = offset.dot(direction);
+  let clearance = offset.length_squared() - (radius * radius);
+
+  if clearance < 0.0 || projection > 0.0 {
+    return None;
+  }
+
+  let discriminant = (projection * projection) - clearance;
+
+  if discriminant < 0.0 {
+    return None;
+  }
+
+  let distance = -projection - discriminant.sqrt();
+  let normal = (offset + (direction * distance)) / radius;
+
+  Some((distance, normal))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const FIRST_LAYER: CollisionLayer = CollisionLayer::from_bits(0b01);
+  const SECOND_LAYER: CollisionLayer = CollisionLayer::from_bits(0b10);
+
+  #[test]
+  fn raycast_miss() {
+    let mut world = ColliderWorld::new();
+    world.insert(Collider::new_static(64.0, 64.0, 16.0, 16.0));
+
+    let hit = raycast(&world, Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0), 256.0, CollisionLayer::ALL);
+
+    assert!(hit.is_none());
+  }
+
+  #[test]
+  fn raycast_first_hit() {
+    let mut world = ColliderWorld::new();
+    world.insert(Collider::new_static(96.0, 0.0, 16.0, 16.0));
+    let near_handle = world.insert(Collider::new_static(64.0, 0.0, 16.0, 16.0));
+
+    let hit = raycast(&world, Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0), 256.0, CollisionLayer::ALL).unwrap();
+
+    assert_eq!(hit.handle(), near_handle);
+    assert_eq!(hit.distance(), 56.0);
+    assert_eq!(hit.point().x, 56.0);
+    assert_eq!(hit.point().y, 0.0);
+    assert_eq!(hit.normal().x, -1.0);
+    assert_eq!(hit.normal().y, 0.0);
+  }
+
+  #[test]
+  fn raycast_max_distance() {
+    let mut world = ColliderWorld::new();
+    world.insert(Collider::new_static(64.0, 0.0, 16.0, 16.0));
+
+    let hit = raycast(&world, Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0), 32.0, CollisionLayer::ALL);
+
+    assert!(hit.is_none());
+  }
+
+  #[test]
+  fn raycast_ignores_colliders_containing_origin() {
+    let mut world = ColliderWorld::new();
+    world.insert(Collider::new(0.0, 0.0, 16.0, 16.0));
+    let wall_handle = world.insert(Collider::new_static(0.0, -64.0, 128.0, 16.0));
+
+    let hit = raycast(&world, Vector2::new(0.0, 0.0), Vector2::new(0.0, -1.0), 256.0, CollisionLayer::ALL).unwrap();
+
+    assert_eq!(hit.handle(), wall_handle);
+    assert_eq!(hit.distance(), 56.0);
+    assert_eq!(hit.normal().y, 1.0);
+  }
+
+  #[test]
+  fn raycast_mask() {
+    let mut world = ColliderWorld::new();
+    world.insert(Collider::new_static(32.0, 0.0, 16.0, 16.0).with_layer(FIRST_LAYER, CollisionLayer::ALL));
+    let far_handle = world.insert(Collider::new_static(64.0, 0.0, 16.0, 16.0).with_layer(SECOND_LAYER, CollisionLayer::ALL));
+
+    let hit = raycast(&world, Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0), 256.0, SECOND_LAYER).unwrap();
+
+    assert_eq!(hit.handle(), far_handle);
+  }
+
+  #[test]
+  fn raycast_circle() {
+    let mut world = ColliderWorld::new();
+    let circle_handle = world.insert(Collider::new_static_circle(64.0, 0.0, 8.0));
+
+    let hit = raycast(&world, Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0), 256.0, CollisionLayer::ALL).unwrap();
+
+    assert_eq!(hit.handle(), circle_handle);
+    assert_eq!(hit.distance(), 56.0);
+    assert_eq!(hit.normal().x, -1.0);
+    assert_eq!(hit.normal().y, 0.0);
+
+    let grazing = raycast(&world, Vector2::new(0.0, 8.0), Vector2::new(1.0, 0.0), 256.0, CollisionLayer::ALL);
+    assert!(grazing.is_some_and(|hit| hit.normal().y == 1.0));
+
+    let miss = raycast(&world, Vector2::new(0.0, 9.0), Vector2::new(1.0, 0.0), 256.0, CollisionLayer::ALL);
+    assert!(miss.is_none());
+  }
+
+  #[test]
+  fn aabb_cast_hit() {
+    let mut world = ColliderWorld::new();
+    let wall_handle = world.insert(Collider::new_static(0.0, 100.0, 256.0, 8.0));
+
+    let hit = aabb_cast(&world, Vector2::new(0.0, 0.0), 16.0, 16.0, Vector2::new(3.0, 4.0), 512.0, CollisionLayer::ALL).unwrap();
+
+    assert_eq!(hit.handle(), wall_handle);
+    assert_eq!(hit.distance(), 110.0);
+    assert_eq!(hit.point().x, 66.0);
+    assert_eq!(hit.point().y, 88.0);
+    assert_eq!(hit.normal().x, 0.0);
+    assert_eq!(hit.normal().y, -1.0);
+  }
+
+  #[test]
+  fn aabb_cast_clips_corner() {
+    let mut world = ColliderWorld::new();
+    world.insert(Collider::new_static(64.0, 12.0, 16.0, 16.0));
+
+    let ray_hit = raycast(&world, Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0), 256.0, CollisionLayer::ALL);
+    let box_hit = aabb_cast(&world, Vector2::new(0.0, 0.0), 16.0, 16.0, Vector2::new(1.0, 0.0), 256.0, CollisionLayer::ALL);
+
+    assert!(ray_hit.is_none());
+    assert!(box_hit.is_some_and(|hit| hit.distance() == 48.0));
+  }
+
+  #[test]
+  fn zero_direction() {
+    let mut world = ColliderWorld::new();
+    world.insert(Collider::new_static(0.0, 0.0, 16.0, 16.0));
+
+    assert!(raycast(&world, Vector2::new(0.0, 32.0), Vector2::new(0.0, 0.0), 256.0, CollisionLayer::ALL).is_none());
+  }
+}
\ No newline at end of file
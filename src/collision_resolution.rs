@@ -0,0 +1,47 @@
+use crate::prelude::Vector2;
+
+#[derive(Clone, Copy)]
+pub struct CollisionResolution {
+  translation: Vector2,
+  velocity: Vector2,
+  normal: Vector2
+}
+
+impl CollisionResolution {
+  pub fn new(translation: Vector2, velocity: Vector2, normal: Vector2) -> Self {
+    Self {
+      translation,
+      velocity,
+      normal
+    }
+  }
+
+  pub fn translation(&self) -> Vector2 {
+    self.translation
+  }
+
+  pub fn velocity(&self) -> Vector2 {
+    self.velocity
+  }
+
+  pub fn normal(&self) -> Vector2 {
+    self.normal
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn constructor() {
+    let resolution = CollisionResolution::new(Vector2::new(4.0, 0.0), Vector2::new(1.0, 2.0), Vector2::new(1.0, 0.0));
+
+    assert_eq!(resolution.translation.x, 4.0);
+    assert_eq!(resolution.translation.y, 0.0);
+    assert_eq!(resolution.velocity.x, 1.0);
+    assert_eq!(resolution.velocity.y, 2.0);
+    assert_eq!(resolution.normal.x, 1.0);
+    assert_eq!(resolution.normal.y, 0.0);
+  }
+}
\ No newline at end of file
@@ -1,29 +1,50 @@
 use crate::prelude::{
+  find_candidate_pairs,
   Collider,
+  ColliderHandle,
+  ColliderShape,
+  ColliderWorld,
   Collision,
-  CollisionDirection
+  CollisionDirection,
+  CollisionResolution,
+  SweptCollision,
+  Vector2
 };
 
-pub fn find_collision(primary_index: usize, secondary_index: usize, primary: &Collider, secondary: &Collider) -> Option<Collision> {
-  let primary_left = primary.location().x() - (primary.width() / 2.0);
-  let primary_right = primary.location().x() + (primary.width() / 2.0);
-  let primary_top = primary.location().y() - (primary.height() / 2.0);
-  let primary_bottom = primary.location().y() + (primary.height() / 2.0);
-
-  let secondary_left = secondary.location().x() - (secondary.width() / 2.0);
-  let secondary_right = secondary.location().x() + (secondary.width() / 2.0);
-  let secondary_top = secondary.location().y() - (secondary.height() / 2.0);
-  let secondary_bottom = secondary.location().y() + (secondary.height() / 2.0);
-
-  let left_overlap = primary_right - secondary_left;
-  let right_overlap = secondary_right - primary_left;
-  let top_overlap = primary_bottom - secondary_top;
-  let bottom_overlap = secondary_bottom - primary_top;
-
-  if left_overlap > 0.0
-  && right_overlap > 0.0
-  && top_overlap > 0.0
-  && bottom_overlap > 0.0 {
+pub fn find_collision(primary_handle: ColliderHandle, secondary_handle: ColliderHandle, primary: &Collider, secondary: &Collider) -> Option<Collision> {
+  let (normal, penetration_depth) = match (primary.shape(), secondary.shape()) {
+    (ColliderShape::Rectangle, ColliderShape::Rectangle) => find_rectangle_contact(primary, secondary)?,
+    (ColliderShape::Circle { radius }, ColliderShape::Rectangle) => find_circle_rectangle_contact(primary, radius, secondary)?,
+    (ColliderShape::Rectangle, ColliderShape::Circle { radius }) => {
+      let (normal, penetration_depth) = find_circle_rectangle_contact(secondary, radius, primary)?;
+      (-normal, penetration_depth)
+    },
+    (ColliderShape::Circle { radius: primary_radius }, ColliderShape::Circle { radius: secondary_radius }) => {
+      find_circle_contact(primary, primary_radius, secondary, secondary_radius)?
+    }
+  };
+
+  Some(Collision::new(
+    primary_handle,
+    secondary_handle,
+    primary.layer(),
+    secondary.layer(),
+    normal,
+    CollisionDirection::from_normal(normal),
+    penetration_depth
+  ))
+}
+
+fn find_rectangle_contact(primary: &Collider, secondary: &Collider) -> Option<(Vector2, f32)> {
+  let primary_rect = primary.rect();
+  let secondary_rect = secondary.rect();
+
+  if primary_rect.intersects(&secondary_rect) {
+    let left_overlap = primary_rect.right() - secondary_rect.left();
+    let right_overlap = secondary_rect.right() - primary_rect.left();
+    let top_overlap = primary_rect.bottom() - secondary_rect.top();
+    let bottom_overlap = secondary_rect.bottom() - primary_rect.top();
+
     let horizontal_overlap = if left_overlap < right_overlap { left_overlap } else { right_overlap };
     let horizontal_direction = if left_overlap < right_overlap { CollisionDirection::Left } else { CollisionDirection::Right };
     
@@ -33,24 +54,182 @@ pub fn find_collision(primary_index: usize, secondary_index: usize, primary: &Co
     let final_overlap = if horizontal_overlap < vertical_overlap { horizontal_overlap } else { vertical_overlap };
     let final_direction = if horizontal_overlap < vertical_overlap { horizontal_direction } else { vertical_direction };
 
-    return Some(Collision::new(primary_index, secondary_index, final_direction, final_overlap));
+    return Some((final_direction.normal(), final_overlap));
   }
 
   None
 }
 
-pub fn find_collisions(colliders: &[Collider]) -> Option<Vec<Collision>> {
-  let mut collisions: Vec<Collision> = vec![];
+fn find_circle_rectangle_contact(circle: &Collider, radius: f32, rectangle: &Collider) -> Option<(Vector2, f32)> {
+  let rect = rectangle.rect();
+  let center = circle.position();
+  let closest = rect.clamp_point(center);
 
-  for (primary_index, primary) in colliders.iter().enumerate() {
-    for (secondary_index, secondary) in colliders.iter().enumerate() {
-      if primary_index != secondary_index {
-        let collision = find_collision(primary_index, secondary_index, primary, secondary);
+  if closest == center {
+    let offset = center - rect.center();
+    let horizontal_overlap = (rect.width() / 2.0) - offset.x.abs() + radius;
+    let vertical_overlap = (rect.height() / 2.0) - offset.y.abs() + radius;
 
-        if let Some(collision) = collision {
-          collisions.push(collision);
-        }
-      }
+    if horizontal_overlap < vertical_overlap {
+      return Some((Vector2::new(if offset.x < 0.0 { -1.0 } else { 1.0 }, 0.0), horizontal_overlap));
+    }
+
+    return Some((Vector2::new(0.0, if offset.y < 0.0 { -1.0 } else { 1.0 }), vertical_overlap));
+  }
+
+  let separation = center - closest;
+  let distance = separation.length();
+
+  if distance >= radius {
+    return None;
+  }
+
+  Some((separation / distance, radius - distance))
+}
+
+fn find_circle_contact(primary: &Collider, primary_radius: f32, secondary: &Collider, secondary_radius: f32) -> Option<(Vector2, f32)> {
+  let separation = primary.position() - secondary.position();
+
+  let distance = separation.length();
+  let radius_sum = primary_radius + secondary_radius;
+
+  if distance >= radius_sum {
+    return None;
+  }
+
+  if distance == 0.0 {
+    return Some((Vector2::new(0.0, -1.0), radius_sum));
+  }
+
+  Some((separation / distance, radius_sum - distance))
+}
+
+pub fn find_swept_collision(
+  primary_handle: ColliderHandle,
+  secondary_handle: ColliderHandle,
+  primary: &Collider,
+  primary_translation: Vector2,
+  secondary: &Collider,
+  secondary_translation: Vector2
+) -> Option<SweptCollision> {
+  let translation = primary_translation - secondary_translation;
+
+  let (time_of_impact, normal) = match (primary.shape(), secondary.shape()) {
+    (ColliderShape::Rectangle, ColliderShape::Rectangle) => find_rectangle_sweep(primary, translation, secondary)?,
+    (ColliderShape::Circle { radius }, ColliderShape::Rectangle) => find_circle_rectangle_sweep(primary, radius, translation, secondary)?,
+    (ColliderShape::Rectangle, ColliderShape::Circle { radius }) => {
+      let (time_of_impact, normal) = find_circle_rectangle_sweep(secondary, radius, -translation, primary)?;
+      (time_of_impact, -normal)
+    },
+    (ColliderShape::Circle { radius: primary_radius }, ColliderShape::Circle { radius: secondary_radius }) => {
+      let time_of_impact = find_ray_circle_entry(primary.position(), translation, secondary.position(), primary_radius + secondary_radius)?;
+      let contact_center = primary.position() + (translation * time_of_impact);
+      (time_of_impact, (contact_center - secondary.position()).normalized())
+    }
+  };
+
+  Some(SweptCollision::new(primary_handle, secondary_handle, time_of_impact, normal, CollisionDirection::from_normal(normal)))
+}
+
+fn find_rectangle_sweep(primary: &Collider, translation: Vector2, secondary: &Collider) -> Option<(f32, Vector2)> {
+  let half_extents = Vector2::new(primary.width() + secondary.width(), primary.height() + secondary.height()) / 2.0;
+
+  find_box_entry(secondary.position() - primary.position(), half_extents, translation)
+}
+
+fn find_circle_rectangle_sweep(circle: &Collider, radius: f32, translation: Vector2, rectangle: &Collider) -> Option<(f32, Vector2)> {
+  let rect = rectangle.rect();
+  let center = circle.position();
+  let half_extents = Vector2::new((rect.width() / 2.0) + radius, (rect.height() / 2.0) + radius);
+
+  let (entry_time, normal) = find_box_entry(rect.center() - center, half_extents, translation)?;
+  let contact_center = center + (translation * entry_time);
+  let closest = rect.clamp_point(contact_center);
+
+  if closest.x == contact_center.x || closest.y == contact_center.y {
+    return Some((entry_time, normal));
+  }
+
+  let corner_time = find_ray_circle_entry(center, translation, closest, radius)?;
+  let corner_center = center + (translation * corner_time);
+
+  Some((corner_time, (corner_center - closest).normalized()))
+}
+
+fn find_box_entry(distance: Vector2, half_extents: Vector2, translation: Vector2) -> Option<(f32, Vector2)> {
+  let (entry_x, exit_x) = find_axis_interval(distance.x, half_extents.x, translation.x)?;
+  let (entry_y, exit_y) = find_axis_interval(distance.y, half_extents.y, translation.y)?;
+
+  let entry_time = if entry_x > entry_y { entry_x } else { entry_y };
+  let exit_time = if exit_x < exit_y { exit_x } else { exit_y };
+
+  if entry_time >= exit_time || !(0.0..=1.0).contains(&entry_time) {
+    return None;
+  }
+
+  let entry_direction = if entry_x > entry_y {
+    if translation.x > 0.0 {
+      CollisionDirection::Left
+    } else {
+      CollisionDirection::Right
+    }
+  } else if translation.y > 0.0 {
+    CollisionDirection::Top
+  } else {
+    CollisionDirection::Bottom
+  };
+
+  Some((entry_time, entry_direction.normal()))
+}
+
+fn find_ray_circle_entry(origin: Vector2, translation: Vector2, center: Vector2, radius: f32) -> Option<f32> {
+  let offset = origin - center;
+
+  let a = translation.length_squared();
+  let b = offset.dot(translation);
+  let c = offset.length_squared() - (radius * radius);
+
+  if a == 0.0 || b >= 0.0 {
+    return None;
+  }
+
+  let discriminant = (b * b) - (a * c);
+
+  if discriminant < 0.0 {
+    return None;
+  }
+
+  let entry_time = (-b - discriminant.sqrt()) / a;
+
+  if !(0.0..=1.0).contains(&entry_time) {
+    return None;
+  }
+
+  Some(entry_time)
+}
+
+fn find_axis_interval(distance: f32, half_extent: f32, translation: f32) -> Option<(f32, f32)> {
+  if translation == 0.0 {
+    if distance.abs() >= half_extent {
+      return None;
+    }
+
+    return Some((f32::NEG_INFINITY, f32::INFINITY));
+  }
+
+  let entry = (distance - (translation.signum() * half_extent)) / translation;
+  let exit = (distance + (translation.signum() * half_extent)) / translation;
+
+  Some((entry, exit))
+}
+
+pub fn find_collisions(world: &ColliderWorld) -> Option<Vec<Collision>> {
+  let mut collisions: Vec<Collision> = vec![];
+
+  for (primary_handle, secondary_handle) in find_candidate_pairs(world) {
+    if let (Some(primary), Some(secondary)) = (world.get(primary_handle), world.get(secondary_handle))
+    && let Some(collision) = find_collision(primary_handle, secondary_handle, primary, secondary) {
+      collisions.push(collision);
     }
   }
 
@@ -61,16 +240,49 @@ pub fn find_collisions(colliders: &[Collider]) -> Option<Vec<Collision>> {
   None
 }
 
+pub fn resolve_collision(collision: &Collision, velocity: Vector2, restitution: f32) -> CollisionResolution {
+  let normal = collision.normal();
+  let translation = normal * collision.penetration_depth();
+
+  CollisionResolution::new(translation, reflect_velocity(velocity, normal, restitution), normal)
+}
+
+pub fn resolve_swept_collision(collision: &SweptCollision, velocity: Vector2, restitution: f32) -> CollisionResolution {
+  let normal = collision.normal();
+
+  CollisionResolution::new(Vector2::ZERO, reflect_velocity(velocity, normal, restitution), normal)
+}
+
+pub fn deflect_from_paddle(normal: Vector2, contact_offset: f32, paddle_half_extent: f32, max_deflection_angle: f32) -> Vector2 {
+  let offset_ratio = (contact_offset / paddle_half_extent).clamp(-1.0, 1.0);
+  let deflection_angle = offset_ratio * max_deflection_angle;
+
+  let tangent = Vector2::new(normal.y.abs(), normal.x.abs());
+
+  (normal * deflection_angle.cos()) + (tangent * deflection_angle.sin())
+}
+
+fn reflect_velocity(velocity: Vector2, normal: Vector2, restitution: f32) -> Vector2 {
+  let approach_speed = velocity.dot(normal);
+
+  if approach_speed >= 0.0 {
+    return velocity;
+  }
+
+  velocity - (normal * ((1.0 + restitution) * approach_speed))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::CollisionLayer;
 
   #[test]
   fn no_collision() {
     let primary_collider = Collider::new(-64.0, 0.0, 32.0, 32.0);
     let secondary_collider = Collider::new(0.0, 0.0, 64.0, 64.0);
 
-    let collision = find_collision(0, 1, &primary_collider, &secondary_collider);
+    let collision = find_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, &secondary_collider);
 
     assert!(collision.is_none());
   }
@@ -80,7 +292,7 @@ mod tests {
     let primary_collider = Collider::new(-32.0, 0.0, 32.0, 32.0);
     let secondary_collider = Collider::new(0.0, 0.0, 64.0, 64.0);
 
-    let collision = find_collision(0, 1, &primary_collider, &secondary_collider);
+    let collision = find_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, &secondary_collider);
     
     match collision {
       Some(collision) => {
@@ -97,7 +309,7 @@ mod tests {
     let primary_collider = Collider::new(32.0, 0.0, 32.0, 32.0);
     let secondary_collider = Collider::new(0.0, 0.0, 64.0, 64.0);
 
-    let collision = find_collision(0, 1, &primary_collider, &secondary_collider);
+    let collision = find_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, &secondary_collider);
     
     match collision {
       Some(collision) => {
@@ -114,7 +326,7 @@ mod tests {
     let primary_collider = Collider::new(0.0, -32.0, 32.0, 32.0);
     let secondary_collider = Collider::new(0.0, 0.0, 64.0, 64.0);
 
-    let collision = find_collision(0, 0, &primary_collider, &secondary_collider);
+    let collision = find_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, &secondary_collider);
     
     match collision {
       Some(collision) => {
@@ -131,7 +343,7 @@ mod tests {
     let primary_collider = Collider::new(0.0, 32.0, 32.0, 32.0);
     let secondary_collider = Collider::new(0.0, 0.0, 64.0, 64.0);
 
-    let collision = find_collision(0, 0, &primary_collider, &secondary_collider);
+    let collision = find_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, &secondary_collider);
     
     match collision {
       Some(collision) => {
@@ -152,34 +364,409 @@ mod tests {
       Collider::new(16.0, 0.0, 32.0, 32.0)
     ];
 
-    let collisions = find_collisions(&colliders);
+    let mut world = ColliderWorld::new();
+    let handles: Vec<ColliderHandle> = colliders.into_iter().map(|collider| world.insert(collider)).collect();
+
+    let collisions = find_collisions(&world);
 
     match collisions {
       Some(collisions) => {
-        assert_eq!(collisions.len(), 4);
+        assert_eq!(collisions.len(), 2);
         
-        assert_eq!(collisions[0].primary_index(), 0);
-        assert_eq!(collisions[0].secondary_index(), 2);
+        assert_eq!(collisions[0].primary_handle(), handles[0]);
+        assert_eq!(collisions[0].secondary_handle(), handles[2]);
         assert_eq!(collisions[0].entry_direction(), CollisionDirection::Right);
         assert_eq!(collisions[0].penetration_depth(), 16.0);
 
-        assert_eq!(collisions[1].primary_index(), 0);
-        assert_eq!(collisions[1].secondary_index(), 3);
+        assert_eq!(collisions[1].primary_handle(), handles[0]);
+        assert_eq!(collisions[1].secondary_handle(), handles[3]);
         assert_eq!(collisions[1].entry_direction(), CollisionDirection::Left);
         assert_eq!(collisions[1].penetration_depth(), 16.0);
+      },
 
-        assert_eq!(collisions[2].primary_index(), 2);
-        assert_eq!(collisions[2].secondary_index(), 0);
-        assert_eq!(collisions[2].entry_direction(), CollisionDirection::Left);
-        assert_eq!(collisions[2].penetration_depth(), 16.0);
+      None => panic!("No Collisions Detected")
+    }
+  }
 
-        assert_eq!(collisions[3].primary_index(), 3);
-        assert_eq!(collisions[3].secondary_index(), 0);
-        assert_eq!(collisions[3].entry_direction(), CollisionDirection::Right);
-        assert_eq!(collisions[3].penetration_depth(), 16.0);
+  #[test]
+  fn static_collisions() {
+    let colliders = vec![
+      Collider::new_static(0.0, 0.0, 64.0, 64.0),
+      Collider::new_static(32.0, 0.0, 64.0, 64.0),
+      Collider::new(-32.0, 0.0, 32.0, 32.0)
+    ];
+
+    let mut world = ColliderWorld::new();
+    let handles: Vec<ColliderHandle> = colliders.into_iter().map(|collider| world.insert(collider)).collect();
+
+    let collisions = find_collisions(&world);
+
+    match collisions {
+      Some(collisions) => {
+        assert_eq!(collisions.len(), 1);
+
+        assert_eq!(collisions[0].primary_handle(), handles[2]);
+        assert_eq!(collisions[0].secondary_handle(), handles[0]);
+        assert_eq!(collisions[0].entry_direction(), CollisionDirection::Left);
+        assert_eq!(collisions[0].penetration_depth(), 16.0);
       },
 
       None => panic!("No Collisions Detected")
     }
   }
+
+  #[test]
+  fn layered_collisions() {
+    let ball_layer = CollisionLayer::from_bits(0b001);
+    let paddle_layer = CollisionLayer::from_bits(0b010);
+    let power_up_layer = CollisionLayer::from_bits(0b100);
+
+    let colliders = vec![
+      Collider::new(0.0, 0.0, 32.0, 32.0).with_layer(ball_layer, paddle_layer | power_up_layer),
+      Collider::new(16.0, 0.0, 32.0, 32.0).with_layer(paddle_layer, ball_layer),
+      Collider::new_static(-16.0, 0.0, 32.0, 32.0).with_layer(power_up_layer, ball_layer),
+      Collider::new_static(0.0, 16.0, 32.0, 32.0).with_layer(power_up_layer, ball_layer)
+    ];
+
+    let mut world = ColliderWorld::new();
+    let handles: Vec<ColliderHandle> = colliders.into_iter().map(|collider| world.insert(collider)).collect();
+
+    let collisions = find_collisions(&world);
+
+    match collisions {
+      Some(collisions) => {
+        assert_eq!(collisions.len(), 3);
+
+        assert_eq!(collisions[0].secondary_handle(), handles[1]);
+        assert_eq!(collisions[0].primary_layer(), ball_layer);
+        assert_eq!(collisions[0].secondary_layer(), paddle_layer);
+
+        assert_eq!(collisions[1].secondary_handle(), handles[2]);
+        assert_eq!(collisions[1].secondary_layer(), power_up_layer);
+
+        assert_eq!(collisions[2].secondary_handle(), handles[3]);
+        assert_eq!(collisions[2].secondary_layer(), power_up_layer);
+      },
+
+      None => panic!("No Collisions Detected")
+    }
+  }
+
+  #[test]
+  fn swept_miss() {
+    let primary_collider = Collider::new(-64.0, -64.0, 16.0, 16.0);
+    let secondary_collider = Collider::new(0.0, 0.0, 16.0, 16.0);
+
+    let collision = find_swept_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, Vector2::new(128.0, 0.0), &secondary_collider, Vector2::new(0.0, 0.0));
+
+    assert!(collision.is_none());
+  }
+
+  #[test]
+  fn swept_out_of_reach() {
+    let primary_collider = Collider::new(-64.0, 0.0, 16.0, 16.0);
+    let secondary_collider = Collider::new(0.0, 0.0, 16.0, 16.0);
+
+    let collision = find_swept_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, Vector2::new(32.0, 0.0), &secondary_collider, Vector2::new(0.0, 0.0));
+
+    assert!(collision.is_none());
+  }
+
+  #[test]
+  fn swept_tunnelling() {
+    let primary_collider = Collider::new(-64.0, 0.0, 16.0, 16.0);
+    let secondary_collider = Collider::new(0.0, 0.0, 16.0, 128.0);
+
+    assert!(find_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &Collider::new(64.0, 0.0, 16.0, 16.0), &secondary_collider).is_none());
+
+    let collision = find_swept_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, Vector2::new(128.0, 0.0), &secondary_collider, Vector2::new(0.0, 0.0));
+
+    match collision {
+      Some(collision) => {
+        assert_eq!(collision.time_of_impact(), 0.375);
+        assert_eq!(collision.normal().x, -1.0);
+        assert_eq!(collision.normal().y, 0.0);
+        assert_eq!(collision.entry_direction(), CollisionDirection::Left);
+      },
+
+      None => panic!("No Collision Detected")
+    }
+  }
+
+  #[test]
+  fn swept_vertical() {
+    let primary_collider = Collider::new(0.0, 64.0, 16.0, 16.0);
+    let secondary_collider = Collider::new(0.0, 0.0, 64.0, 16.0);
+
+    let collision = find_swept_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, Vector2::new(0.0, -96.0), &secondary_collider, Vector2::new(0.0, 0.0));
+
+    match collision {
+      Some(collision) => {
+        assert_eq!(collision.time_of_impact(), 0.5);
+        assert_eq!(collision.normal().x, 0.0);
+        assert_eq!(collision.normal().y, 1.0);
+        assert_eq!(collision.entry_direction(), CollisionDirection::Bottom);
+      },
+
+      None => panic!("No Collision Detected")
+    }
+  }
+
+  #[test]
+  fn swept_moving_secondary() {
+    let primary_collider = Collider::new(0.0, 0.0, 16.0, 16.0);
+    let secondary_collider = Collider::new(64.0, 0.0, 16.0, 16.0);
+
+    let collision = find_swept_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, Vector2::new(24.0, 0.0), &secondary_collider, Vector2::new(-24.0, 0.0));
+
+    match collision {
+      Some(collision) => {
+        assert_eq!(collision.time_of_impact(), 1.0);
+        assert_eq!(collision.entry_direction(), CollisionDirection::Left);
+      },
+
+      None => panic!("No Collision Detected")
+    }
+  }
+
+  #[test]
+  fn swept_moving_apart() {
+    let primary_collider = Collider::new(0.0, 0.0, 16.0, 16.0);
+    let secondary_collider = Collider::new(16.0, 0.0, 16.0, 16.0);
+
+    let collision = find_swept_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, Vector2::new(-16.0, 0.0), &secondary_collider, Vector2::new(0.0, 0.0));
+
+    assert!(collision.is_none());
+  }
+
+  #[test]
+  fn resolution_pushes_out() {
+    let primary_collider = Collider::new(-32.0, 0.0, 32.0, 32.0);
+    let secondary_collider = Collider::new(0.0, 0.0, 64.0, 64.0);
+
+    let collision = find_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, &secondary_collider).unwrap();
+    let resolution = resolve_collision(&collision, Vector2::new(2.0, 1.0), 1.0);
+
+    assert_eq!(resolution.translation().x, -16.0);
+    assert_eq!(resolution.translation().y, 0.0);
+    assert_eq!(resolution.velocity().x, -2.0);
+    assert_eq!(resolution.velocity().y, 1.0);
+    assert_eq!(resolution.normal().x, -1.0);
+  }
+
+  #[test]
+  fn resolution_restitution() {
+    let primary_collider = Collider::new(0.0, -32.0, 32.0, 32.0);
+    let secondary_collider = Collider::new(0.0, 0.0, 64.0, 64.0);
+
+    let collision = find_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, &secondary_collider).unwrap();
+
+    let inelastic = resolve_collision(&collision, Vector2::new(1.0, 4.0), 0.0);
+    assert_eq!(inelastic.velocity().x, 1.0);
+    assert_eq!(inelastic.velocity().y, 0.0);
+
+    let damped = resolve_collision(&collision, Vector2::new(1.0, 4.0), 0.5);
+    assert_eq!(damped.velocity().x, 1.0);
+    assert_eq!(damped.velocity().y, -2.0);
+  }
+
+  #[test]
+  fn resolution_keeps_separating_velocity() {
+    let primary_collider = Collider::new(-32.0, 0.0, 32.0, 32.0);
+    let secondary_collider = Collider::new(0.0, 0.0, 64.0, 64.0);
+
+    let collision = find_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, &secondary_collider).unwrap();
+    let resolution = resolve_collision(&collision, Vector2::new(-2.0, 1.0), 1.0);
+
+    assert_eq!(resolution.velocity().x, -2.0);
+    assert_eq!(resolution.velocity().y, 1.0);
+  }
+
+  #[test]
+  fn swept_resolution() {
+    let primary_collider = Collider::new(-64.0, 0.0, 16.0, 16.0);
+    let secondary_collider = Collider::new(0.0, 0.0, 16.0, 16.0);
+
+    let collision = find_swept_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, Vector2::new(128.0, 0.0), &secondary_collider, Vector2::new(0.0, 0.0)).unwrap();
+    let resolution = resolve_swept_collision(&collision, Vector2::new(1.0, 1.0), 1.0);
+
+    assert_eq!(resolution.translation().x, 0.0);
+    assert_eq!(resolution.translation().y, 0.0);
+    assert_eq!(resolution.velocity().x, -1.0);
+    assert_eq!(resolution.velocity().y, 1.0);
+  }
+
+  #[test]
+  fn paddle_deflection() {
+    let max_angle = std::f32::consts::FRAC_PI_4;
+
+    let centered = deflect_from_paddle(Vector2::new(1.0, 0.0), 0.0, 64.0, max_angle);
+    assert_eq!(centered.x, 1.0);
+    assert_eq!(centered.y, 0.0);
+
+    let upper_edge = deflect_from_paddle(Vector2::new(-1.0, 0.0), -64.0, 64.0, max_angle);
+    assert!(upper_edge.x < 0.0);
+    assert!(upper_edge.y < 0.0);
+    assert!((upper_edge.x.abs() - upper_edge.y.abs()).abs() < 0.0001);
+
+    let clamped = deflect_from_paddle(Vector2::new(1.0, 0.0), 256.0, 64.0, max_angle);
+    assert!((clamped.x - upper_edge.x.abs()).abs() < 0.0001);
+    assert!((clamped.y - upper_edge.y.abs()).abs() < 0.0001);
+  }
+
+  #[test]
+  fn circle_rectangle_face_collision() {
+    let primary_collider = Collider::new_circle(-36.0, 0.0, 8.0);
+    let secondary_collider = Collider::new(0.0, 0.0, 64.0, 64.0);
+
+    let collision = find_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, &secondary_collider).unwrap();
+
+    assert_eq!(collision.entry_direction(), CollisionDirection::Left);
+    assert_eq!(collision.normal().x, -1.0);
+    assert_eq!(collision.normal().y, 0.0);
+    assert_eq!(collision.penetration_depth(), 4.0);
+  }
+
+  #[test]
+  fn circle_rectangle_corner_collision() {
+    let primary_collider = Collider::new_circle(35.0, -36.0, 8.0);
+    let secondary_collider = Collider::new(0.0, 0.0, 64.0, 64.0);
+
+    let collision = find_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, &secondary_collider).unwrap();
+
+    assert_eq!(collision.normal().x, 0.6);
+    assert_eq!(collision.normal().y, -0.8);
+    assert_eq!(collision.entry_direction(), CollisionDirection::Top);
+    assert_eq!(collision.penetration_depth(), 3.0);
+  }
+
+  #[test]
+  fn circle_misses_rectangle_corner() {
+    let primary_collider = Collider::new_circle(38.0, -38.0, 8.0);
+    let secondary_collider = Collider::new(0.0, 0.0, 64.0, 64.0);
+
+    let collision = find_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, &secondary_collider);
+
+    assert!(collision.is_none());
+  }
+
+  #[test]
+  fn rectangle_circle_collision() {
+    let primary_collider = Collider::new(0.0, 0.0, 64.0, 64.0);
+    let secondary_collider = Collider::new_circle(0.0, 38.0, 8.0);
+
+    let collision = find_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, &secondary_collider).unwrap();
+
+    assert_eq!(collision.entry_direction(), CollisionDirection::Top);
+    assert_eq!(collision.normal().x, 0.0);
+    assert_eq!(collision.normal().y, -1.0);
+    assert_eq!(collision.penetration_depth(), 2.0);
+  }
+
+  #[test]
+  fn circle_inside_rectangle() {
+    let primary_collider = Collider::new_circle(24.0, 0.0, 8.0);
+    let secondary_collider = Collider::new(0.0, 0.0, 64.0, 64.0);
+
+    let collision = find_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, &secondary_collider).unwrap();
+
+    assert_eq!(collision.entry_direction(), CollisionDirection::Right);
+    assert_eq!(collision.penetration_depth(), 16.0);
+  }
+
+  #[test]
+  fn circle_circle_collision() {
+    let primary_collider = Collider::new_circle(0.0, 0.0, 8.0);
+    let secondary_collider = Collider::new_circle(8.0, 6.0, 8.0);
+
+    let collision = find_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, &secondary_collider).unwrap();
+
+    assert_eq!(collision.normal().x, -0.8);
+    assert_eq!(collision.normal().y, -0.6);
+    assert_eq!(collision.entry_direction(), CollisionDirection::Left);
+    assert_eq!(collision.penetration_depth(), 6.0);
+  }
+
+  #[test]
+  fn circle_circle_miss() {
+    let primary_collider = Collider::new_circle(0.0, 0.0, 8.0);
+    let secondary_collider = Collider::new_circle(16.0, 0.0, 8.0);
+
+    let collision = find_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, &secondary_collider);
+
+    assert!(collision.is_none());
+  }
+
+  #[test]
+  fn corner_resolution_reflects_along_normal() {
+    let primary_collider = Collider::new_circle(35.0, -36.0, 8.0);
+    let secondary_collider = Collider::new(0.0, 0.0, 64.0, 64.0);
+
+    let collision = find_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, &secondary_collider).unwrap();
+    let resolution = resolve_collision(&collision, Vector2::new(0.0, 1.0), 1.0);
+
+    assert!((resolution.velocity().x - 0.96).abs() < 0.0001);
+    assert!((resolution.velocity().y - -0.28).abs() < 0.0001);
+  }
+
+  #[test]
+  fn swept_circle_rectangle_face() {
+    let primary_collider = Collider::new_circle(-64.0, 0.0, 8.0);
+    let secondary_collider = Collider::new(0.0, 0.0, 64.0, 64.0);
+
+    let collision = find_swept_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, Vector2::new(64.0, 0.0), &secondary_collider, Vector2::new(0.0, 0.0)).unwrap();
+
+    assert_eq!(collision.time_of_impact(), 0.375);
+    assert_eq!(collision.normal().x, -1.0);
+    assert_eq!(collision.normal().y, 0.0);
+    assert_eq!(collision.entry_direction(), CollisionDirection::Left);
+  }
+
+  #[test]
+  fn swept_circle_rectangle_corner() {
+    let primary_collider = Collider::new_circle(0.0, 0.0, 8.0);
+    let secondary_collider = Collider::new(30.0, 30.0, 32.0, 32.0);
+
+    let collision = find_swept_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, Vector2::new(20.0, 20.0), &secondary_collider, Vector2::new(0.0, 0.0)).unwrap();
+
+    assert!((collision.time_of_impact() - 0.4172).abs() < 0.0001);
+    assert!((collision.normal().x - -std::f32::consts::FRAC_1_SQRT_2).abs() < 0.0001);
+    assert!((collision.normal().y - -std::f32::consts::FRAC_1_SQRT_2).abs() < 0.0001);
+  }
+
+  #[test]
+  fn swept_circle_misses_rectangle_corner() {
+    let primary_collider = Collider::new_circle(0.0, 13.0, 8.0);
+    let secondary_collider = Collider::new(30.0, 30.0, 32.0, 32.0);
+
+    let collision = find_swept_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, Vector2::new(12.0, -12.0), &secondary_collider, Vector2::new(0.0, 0.0));
+
+    assert!(collision.is_none());
+  }
+
+  #[test]
+  fn swept_rectangle_circle() {
+    let primary_collider = Collider::new(0.0, -64.0, 32.0, 32.0);
+    let secondary_collider = Collider::new_circle(0.0, 0.0, 8.0);
+
+    let collision = find_swept_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, Vector2::new(0.0, 64.0), &secondary_collider, Vector2::new(0.0, 0.0)).unwrap();
+
+    assert_eq!(collision.time_of_impact(), 0.625);
+    assert_eq!(collision.normal().x, 0.0);
+    assert_eq!(collision.normal().y, -1.0);
+    assert_eq!(collision.entry_direction(), CollisionDirection::Top);
+  }
+
+  #[test]
+  fn swept_circle_circle() {
+    let primary_collider = Collider::new_circle(-32.0, 0.0, 8.0);
+    let secondary_collider = Collider::new_circle(0.0, 9.6, 8.0);
+
+    let collision = find_swept_collision(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), &primary_collider, Vector2::new(32.0, 0.0), &secondary_collider, Vector2::new(0.0, 0.0)).unwrap();
+
+    assert!((collision.time_of_impact() - 0.6).abs() < 0.0001);
+    assert!((collision.normal().x - -0.8).abs() < 0.0001);
+    assert!((collision.normal().y - -0.6).abs() < 0.0001);
+    assert_eq!(collision.entry_direction(), CollisionDirection::Left);
+  }
 }
\ No newline at end of file
@@ -0,0 +1,148 @@
+use std::collections::BTreeSet;
+
+use crate::prelude::{
+  ColliderHandle,
+  Collision,
+  CollisionEvent,
+  CollisionPhase
+};
+
+#[derive(Default)]
+pub struct CollisionTracker {
+  active_pairs: BTreeSet<(ColliderHandle, ColliderHandle)>
+}
+
+impl CollisionTracker {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn is_touching(&self, primary_handle: ColliderHandle, secondary_handle: ColliderHandle) -> bool {
+    self.active_pairs.contains(&(primary_handle, secondary_handle))
+  }
+
+  pub fn update(&mut self, collisions: &[Collision]) -> Vec<CollisionEvent> {
+    let mut events = vec![];
+    let mut current_pairs = BTreeSet::new();
+
+    for collision in collisions {
+      let pair = (collision.primary_handle(), collision.secondary_handle());
+
+      if !current_pairs.insert(pair) {
+        continue;
+      }
+
+      let phase = if self.active_pairs.contains(&pair) {
+        CollisionPhase::Stay
+      } else {
+        CollisionPhase::Enter
+      };
+
+      events.push(CollisionEvent::new(pair.0, pair.1, phase));
+    }
+
+    for pair in self.active_pairs.difference(&current_pairs) {
+      events.push(CollisionEvent::new(pair.0, pair.1, CollisionPhase::Exit));
+    }
+
+    self.active_pairs = current_pairs;
+
+    events
+  }
+
+  pub fn clear(&mut self) {
+    self.active_pairs.clear();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::prelude::{CollisionDirection, CollisionLayer};
+
+  fn collision(primary_index: usize, secondary_index: usize) -> Collision {
+    Collision::new(
+      ColliderHandle::new(primary_index, 0),
+      ColliderHandle::new(secondary_index, 0),
+      CollisionLayer::ALL,
+      CollisionLayer::ALL,
+      CollisionDirection::Left.normal(),
+      CollisionDirection::Left,
+      1.0
+    )
+  }
+
+  #[test]
+  fn enter() {
+    let mut tracker = CollisionTracker::new();
+
+    let events = tracker.update(&[collision(0, 1)]);
+
+    assert_eq!(events, vec![
+      CollisionEvent::new(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), CollisionPhase::Enter)
+    ]);
+
+    assert!(tracker.is_touching(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0)));
+  }
+
+  #[test]
+  fn stay() {
+    let mut tracker = CollisionTracker::new();
+
+    tracker.update(&[collision(0, 1)]);
+    let events = tracker.update(&[collision(0, 1)]);
+
+    assert_eq!(events, vec![
+      CollisionEvent::new(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), CollisionPhase::Stay)
+    ]);
+  }
+
+  #[test]
+  fn exit() {
+    let mut tracker = CollisionTracker::new();
+
+    tracker.update(&[collision(0, 1)]);
+    let events = tracker.update(&[]);
+
+    assert_eq!(events, vec![
+      CollisionEvent::new(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), CollisionPhase::Exit)
+    ]);
+
+    assert!(!tracker.is_touching(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0)));
+    assert!(tracker.update(&[]).is_empty());
+  }
+
+  #[test]
+  fn mixed_phases() {
+    let mut tracker = CollisionTracker::new();
+
+    tracker.update(&[collision(0, 1), collision(0, 2)]);
+    let events = tracker.update(&[collision(0, 2), collision(0, 3)]);
+
+    assert_eq!(events, vec![
+      CollisionEvent::new(ColliderHandle::new(0, 0), ColliderHandle::new(2, 0), CollisionPhase::Stay),
+      CollisionEvent::new(ColliderHandle::new(0, 0), ColliderHandle::new(3, 0), CollisionPhase::Enter),
+      CollisionEvent::new(ColliderHandle::new(0, 0), ColliderHandle::new(1, 0), CollisionPhase::Exit)
+    ]);
+  }
+
+  #[test]
+  fn duplicate_collisions() {
+    let mut tracker = CollisionTracker::new();
+
+    let events = tracker.update(&[collision(0, 1), collision(0, 1)]);
+
+    assert_eq!(events.len(), 1);
+  }
+
+  #[test]
+  fn clearing() {
+    let mut tracker = CollisionTracker::new();
+
+    tracker.update(&[collision(0, 1)]);
+    tracker.clear();
+    let events = tracker.update(&[collision(0, 1)]);
+
+    assert_eq!(events[0].phase(), CollisionPhase::Enter);
+  }
+}
\ No newline at end of file
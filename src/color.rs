@@ -0,0 +1,49 @@
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Color {
+  pub r: f32,
+  pub g: f32,
+  pub b: f32,
+  pub a: f32
+}
+
+impl Color {
+  pub const WHITE: Self = Self::rgb(1.0, 1.0, 1.0);
+  pub const BLACK: Self = Self::rgb(0.0, 0.0, 0.0);
+  pub const RED: Self = Self::rgb(1.0, 0.0, 0.0);
+  pub const GREEN: Self = Self::rgb(0.0, 1.0, 0.0);
+  pub const BLUE: Self = Self::rgb(0.0, 0.0, 1.0);
+  pub const YELLOW: Self = Self::rgb(1.0, 1.0, 0.0);
+  pub const TRANSPARENT: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+  pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+    Self { r, g, b, a }
+  }
+
+  pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+    Self::new(r, g, b, 1.0)
+  }
+
+  pub fn with_alpha(&self, a: f32) -> Self {
+    Self::new(self.r, self.g, self.b, a)
+  }
+}
+
+impl Default for Color {
+  fn default() -> Self {
+    Self::WHITE
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn construction() {
+    let color = Color::rgb(0.25, 0.5, 0.75);
+
+    assert_eq!(color, Color::new(0.25, 0.5, 0.75, 1.0));
+    assert_eq!(color.with_alpha(0.5).a, 0.5);
+    assert_eq!(Color::default(), Color::WHITE);
+  }
+}
\ No newline at end of file
@@ -0,0 +1,123 @@
+use crate::prelude::{Collider, ColliderShape, CollisionPhase, Color, ShapeRenderer, Simulation, Vector2};
+
+const OUTLINE_THICKNESS: f32 = 1.0;
+const NORMAL_LENGTH: f32 = 16.0;
+const CONTACT_RADIUS: f32 = 3.0;
+const VELOCITY_LOOKAHEAD_MILLIS: f32 = 100.0;
+
+const STATIC_COLOR: Color = Color::new(0.0, 1.0, 0.0, 0.75);
+const DYNAMIC_COLOR: Color = Color::new(1.0, 1.0, 0.0, 0.75);
+const CONTACT_COLOR: Color = Color::RED;
+const ENTER_COLOR: Color = Color::WHITE;
+const PENETRATION_COLOR: Color = Color::new(1.0, 0.0, 1.0, 1.0);
+const VELOCITY_COLOR: Color = Color::new(0.0, 1.0, 1.0, 1.0);
+
+#[derive(Default)]
+pub struct DebugOverlay {
+  enabled: bool
+}
+
+impl DebugOverlay {
+  pub fn is_enabled(&self) -> bool {
+    self.enabled
+  }
+
+  pub fn set_enabled(&mut self, enabled: bool) {
+    self.enabled = enabled;
+  }
+
+  pub fn toggle(&mut self) {
+    self.enabled = !self.enabled;
+  }
+
+  pub fn draw(&self, simulation: &Simulation, shape_renderer: &mut ShapeRenderer) {
+    if !self.enabled {
+      return;
+    }
+
+    let colliders = simulation.colliders();
+
+    for (_, collider) in colliders.iter() {
+      let color = if collider.is_static() { STATIC_COLOR } else { DYNAMIC_COLOR };
+
+      match collider.shape() {
+        ColliderShape::Rectangle => shape_renderer.stroke_rect(collider.rect(), OUTLINE_THICKNESS, color),
+        ColliderShape::Circle { radius } => shape_renderer.stroke_circle(collider.position(), radius, OUTLINE_THICKNESS, color)
+      }
+    }
+
+    for event in simulation.collision_events() {
+      if event.phase() != CollisionPhase::Enter {
+        continue;
+      }
+
+      if let Some(collider) = colliders.get(event.secondary_handle()) {
+        shape_renderer.stroke_rect(collider.rect(), OUTLINE_THICKNESS * 3.0, ENTER_COLOR);
+      }
+    }
+
+    for collision in simulation.collisions() {
+      let (Some(primary), Some(secondary)) = (colliders.get(collision.primary_handle()), colliders.get(collision.secondary_handle())) else {
+        continue;
+      };
+
+      let contact = contact_point(primary, secondary);
+      let normal = collision.normal();
+
+      shape_renderer.fill_circle(contact, CONTACT_RADIUS, CONTACT_COLOR);
+      shape_renderer.line(contact, contact + (normal * NORMAL_LENGTH), OUTLINE_THICKNESS, CONTACT_COLOR);
+      shape_renderer.line(contact, contact - (normal * collision.penetration_depth()), OUTLINE_THICKNESS * 3.0, PENETRATION_COLOR);
+    }
+
+    for (handle, velocity) in simulation.collider_velocities() {
+      if velocity == Vector2::ZERO {
+        continue;
+      }
+
+      if let Some(collider) = colliders.get(handle) {
+        let position = collider.position();
+        shape_renderer.line(position, position + (velocity * VELOCITY_LOOKAHEAD_MILLIS), OUTLINE_THICKNESS, VELOCITY_COLOR);
+      }
+    }
+  }
+}
+
+fn contact_point(primary: &Collider, secondary: &Collider) -> Vector2 {
+  match primary.rect().intersection(&secondary.rect()) {
+    Some(overlap) => overlap.center(),
+    None => primary.position().lerp(secondary.position(), 0.5)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn toggling() {
+    let mut debug_overlay = DebugOverlay::default();
+    assert!(!debug_overlay.is_enabled());
+
+    debug_overlay.toggle();
+    assert!(debug_overlay.is_enabled());
+
+    debug_overlay.set_enabled(false);
+    assert!(!debug_overlay.is_enabled());
+  }
+
+  #[test]
+  fn overlapping_contact_point() {
+    let primary = Collider::new(10.0, 10.0, 20.0, 20.0);
+    let secondary = Collider::new(26.0, 10.0, 20.0, 20.0);
+
+    assert_eq!(contact_point(&primary, &secondary), Vector2::new(18.0, 10.0));
+  }
+
+  #[test]
+  fn touching_contact_point() {
+    let primary = Collider::new(0.0, 0.0, 10.0, 10.0);
+    let secondary = Collider::new(10.0, 0.0, 10.0, 10.0);
+
+    assert_eq!(contact_point(&primary, &secondary), Vector2::new(5.0, 0.0));
+  }
+}
\ No newline at end of file
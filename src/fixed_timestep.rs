@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+const MAX_TICKS_PER_FRAME: u32 = 8;
+
+pub struct FixedTimestep {
+  tick_duration: Duration,
+  accumulator: Duration
+}
+
+impl FixedTimestep {
+  pub fn new(tick_rate: u32) -> Result<Self, String> {
+    if tick_rate == 0 {
+      return Err("Tick rate must be greater than zero".to_string());
+    }
+
+    Ok(
+      Self {
+        tick_duration: Duration::from_secs(1) / tick_rate,
+        accumulator: Duration::ZERO
+      }
+    )
+  }
+
+  pub fn tick_duration(&self) -> Duration {
+    self.tick_duration
+  }
+
+  pub fn tick_millis(&self) -> f32 {
+    self.tick_duration.as_secs_f32() * 1000.0
+  }
+
+  pub fn advance(&mut self, frame_duration: Duration) -> u32 {
+    self.accumulator += frame_duration;
+
+    let mut ticks = 0;
+    while self.accumulator >= self.tick_duration {
+      self.accumulator -= self.tick_duration;
+      ticks += 1;
+
+      if ticks == MAX_TICKS_PER_FRAME {
+        self.accumulator = Duration::ZERO;
+        break;
+      }
+    }
+
+    ticks
+  }
+
+  pub fn alpha(&self) -> f32 {
+    self.accumulator.as_secs_f32() / self.tick_duration.as_secs_f32()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn constructor() {
+    let fixed_timestep = FixedTimestep::new(100).unwrap();
+
+    assert_eq!(fixed_timestep.tick_duration(), Duration::from_millis(10));
+    assert_eq!(fixed_timestep.tick_millis(), 10.0);
+    assert_eq!(fixed_timestep.alpha(), 0.0);
+  }
+
+  #[test]
+  fn zero_tick_rate() {
+    assert!(FixedTimestep::new(0).is_err());
+  }
+
+  #[test]
+  fn partial_tick() {
+    let mut fixed_timestep = FixedTimestep::new(100).unwrap();
+
+    let ticks = fixed_timestep.advance(Duration::from_millis(5));
+
+    assert_eq!(ticks, 0);
+    assert_eq!(fixed_timestep.alpha(), 0.5);
+  }
+
+  #[test]
+  fn accumulated_ticks() {
+    let mut fixed_timestep = FixedTimestep::new(125).unwrap();
+
+    assert_eq!(fixed_timestep.advance(Duration::from_millis(5)), 0);
+    assert_eq!(fixed_timestep.advance(Duration::from_millis(5)), 1);
+    assert_eq!(fixed_timestep.advance(Duration::from_millis(18)), 2);
+    assert_eq!(fixed_timestep.alpha(), 0.5);
+  }
+
+  #[test]
+  fn frame_stall() {
+    let mut fixed_timestep = FixedTimestep::new(100).unwrap();
+
+    let ticks = fixed_timestep.advance(Duration::from_secs(2));
+
+    assert_eq!(ticks, MAX_TICKS_PER_FRAME);
+    assert_eq!(fixed_timestep.alpha(), 0.0);
+  }
+}
\ No newline at end of file
@@ -0,0 +1,49 @@
+#[derive(Clone, Copy, Default)]
+pub struct Inputs {
+  pub left_paddle_up: bool,
+  pub left_paddle_down: bool
+}
+
+impl Inputs {
+  pub fn left_paddle_direction(&self) -> f32 {
+    let mut direction = 0.0;
+
+    if self.left_paddle_up {
+      direction -= 1.0;
+    }
+
+    if self.left_paddle_down {
+      direction += 1.0;
+    }
+
+    direction
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_input() {
+    let inputs = Inputs::default();
+
+    assert_eq!(inputs.left_paddle_direction(), 0.0);
+  }
+
+  #[test]
+  fn single_direction() {
+    let up = Inputs { left_paddle_up: true, left_paddle_down: false };
+    let down = Inputs { left_paddle_up: false, left_paddle_down: true };
+
+    assert_eq!(up.left_paddle_direction(), -1.0);
+    assert_eq!(down.left_paddle_direction(), 1.0);
+  }
+
+  #[test]
+  fn opposing_directions() {
+    let inputs = Inputs { left_paddle_up: true, left_paddle_down: true };
+
+    assert_eq!(inputs.left_paddle_direction(), 0.0);
+  }
+}
\ No newline at end of file
@@ -1,59 +1,220 @@
-use std::{path::Path, time::Instant};
-use sdl2::{event::Event, keyboard::Keycode};
+use std::{path::Path, time::{Instant, SystemTime, UNIX_EPOCH}};
+use sdl2::{event::Event, keyboard::Keycode, video::SwapInterval};
 
 mod math;
 mod vertex_data;
 mod buffer_object;
 mod vertex_array;
+mod shader_stage;
+mod shader_error;
 mod shader;
+mod uniform_value;
 mod shader_program;
+mod texture_filter;
 mod texture;
-mod location;
+mod texture_atlas;
+mod sprite;
+mod animation_mode;
+mod animation_frame;
+mod animation;
+mod animation_player;
+mod text_alignment;
+mod text_style;
+mod bitmap_font;
+mod shape_data;
+mod shape_renderer;
+mod transform;
+mod body_type;
+mod collision_layer;
+mod collider_shape;
 mod collider;
+mod collider_handle;
+mod collider_world;
 mod collision;
 mod collision_direction;
+mod collision_resolution;
+mod swept_collision;
+mod broad_phase;
 mod collision_system;
+mod cast_hit;
+mod collision_query;
+mod collision_phase;
+mod collision_event;
+mod collision_tracker;
+mod fixed_timestep;
 mod frame_limiter;
-mod quad;
+mod random;
+mod color;
+mod sprite_batch;
+mod inputs;
+mod player;
+mod serve_direction;
+mod match_rules;
+mod match_event;
+mod scoreboard;
+mod simulation;
+mod debug_overlay;
 
 mod prelude {
   pub const WINDOW_WIDTH: u32 = 800;
   pub const WINDOW_HEIGHT: u32 = 600;
+  pub const TICK_RATE: u32 = 120;
+  pub const FALLBACK_FRAME_RATE: u32 = 60;
+  pub const NET_SPACING: u32 = 32;
+  pub const PADDLE_FLASH_MILLIS: f32 = 120.0;
+  pub const POWER_UP_FADE_MILLIS: f32 = 500.0;
+  pub const LEFT_PLAYER_COLOR: Color = Color::rgb(0.5, 0.8, 1.0);
+  pub const RIGHT_PLAYER_COLOR: Color = Color::rgb(1.0, 0.6, 0.5);
+  pub const MATCH_OVER_TINT: Color = Color::rgb(0.4, 0.4, 0.4);
 
   pub use crate::math::Vector2;
+  pub use crate::math::Vector3;
   pub use crate::math::Vector4;
+  pub use crate::math::Matrix3;
   pub use crate::math::Matrix4;
-  pub use crate::vertex_data::generate_textured_vertex_data;
+  pub use crate::math::Rect;
+  pub use crate::vertex_data::{generate_batch_element_data, generate_sprite_vertex_data};
   pub use crate::buffer_object::BufferObject;
   pub use crate::vertex_array::VertexArray;
+  pub use crate::shader_stage::ShaderStage;
+  pub use crate::shader_error::ShaderError;
   pub use crate::shader::Shader;
+  pub use crate::uniform_value::UniformValue;
   pub use crate::shader_program::{ShaderProgram, set_model_matrix, set_view_matrix, set_projection_matrix};
+  pub use crate::texture_filter::TextureFilter;
   pub use crate::texture::Texture;
-  pub use crate::location::Location;
+  pub use crate::texture_atlas::{TextureAtlas, TextureAtlasBuilder};
+  pub use crate::sprite::Sprite;
+  pub use crate::animation_mode::AnimationMode;
+  pub use crate::animation_frame::AnimationFrame;
+  pub use crate::animation::Animation;
+  pub use crate::animation_player::AnimationPlayer;
+  pub use crate::text_alignment::TextAlignment;
+  pub use crate::text_style::TextStyle;
+  pub use crate::bitmap_font::BitmapFont;
+  pub use crate::shape_data::{
+    generate_circle_outline_vertex_data,
+    generate_circle_vertex_data,
+    generate_line_vertex_data,
+    generate_rect_outline_vertex_data,
+    generate_rect_vertex_data
+  };
+  pub use crate::shape_renderer::ShapeRenderer;
+  pub use crate::transform::Transform;
+  pub use crate::body_type::BodyType;
+  pub use crate::collision_layer::CollisionLayer;
+  pub use crate::collider_shape::ColliderShape;
   pub use crate::collider::Collider;
+  pub use crate::collider_handle::ColliderHandle;
+  pub use crate::collider_world::ColliderWorld;
   pub use crate::collision::Collision;
   pub use crate::collision_direction::CollisionDirection;
-  pub use crate::collision_system::find_collisions;
+  pub use crate::collision_resolution::CollisionResolution;
+  pub use crate::swept_collision::SweptCollision;
+  pub use crate::broad_phase::find_candidate_pairs;
+  pub use crate::cast_hit::CastHit;
+  pub use crate::collision_query::{aabb_cast, raycast};
+  pub use crate::collision_phase::CollisionPhase;
+  pub use crate::collision_event::CollisionEvent;
+  pub use crate::collision_tracker::CollisionTracker;
+  pub use crate::collision_system::{
+    deflect_from_paddle,
+    find_collision,
+    find_collisions,
+    find_swept_collision,
+    resolve_collision,
+    resolve_swept_collision
+  };
+  pub use crate::fixed_timestep::FixedTimestep;
   pub use crate::frame_limiter::limit_frame_rate;
-  pub use crate::quad::{Quad, render_textured_quad};
+  pub use crate::random::Random;
+  pub use crate::color::Color;
+  pub use crate::sprite_batch::SpriteBatch;
+  pub use crate::inputs::Inputs;
+  pub use crate::player::Player;
+  pub use crate::serve_direction::ServeDirection;
+  pub use crate::match_rules::MatchRules;
+  pub use crate::match_event::MatchEvent;
+  pub use crate::scoreboard::Scoreboard;
+  pub use crate::simulation::Simulation;
+  pub use crate::debug_overlay::DebugOverlay;
 }
 
-use prelude::{
+pub use prelude::{
+  aabb_cast,
+  deflect_from_paddle,
+  find_candidate_pairs,
+  find_collision,
   find_collisions,
-  limit_frame_rate,
-  set_model_matrix,
-  set_projection_matrix,
-  set_view_matrix,
+  find_swept_collision,
+  raycast,
+  resolve_collision,
+  resolve_swept_collision,
+  Animation,
+  AnimationFrame,
+  AnimationMode,
+  AnimationPlayer,
+  BitmapFont,
+  BodyType,
+  CastHit,
   Collider,
+  ColliderHandle,
+  ColliderShape,
+  ColliderWorld,
+  Collision,
   CollisionDirection,
-  Location,
+  CollisionEvent,
+  CollisionLayer,
+  CollisionPhase,
+  CollisionResolution,
+  CollisionTracker,
+  Color,
+  DebugOverlay,
+  FixedTimestep,
+  Inputs,
+  MatchEvent,
+  MatchRules,
+  Matrix3,
   Matrix4,
+  Player,
+  Random,
+  Rect,
+  Scoreboard,
+  ServeDirection,
+  ShaderError,
+  ShaderStage,
+  ShapeRenderer,
+  Simulation,
+  Sprite,
+  SpriteBatch,
+  SweptCollision,
+  TextAlignment,
+  TextStyle,
+  Texture,
+  TextureAtlas,
+  TextureAtlasBuilder,
+  TextureFilter,
+  Transform,
+  UniformValue,
+  Vector2,
+  Vector3,
+  Vector4
+};
+
+use prelude::{
+  limit_frame_rate,
+  set_projection_matrix,
+  set_view_matrix,
   Shader,
   ShaderProgram,
-  Vector2,
-  Texture,
-  Quad,
-  render_textured_quad,
+  FALLBACK_FRAME_RATE,
+  LEFT_PLAYER_COLOR,
+  MATCH_OVER_TINT,
+  NET_SPACING,
+  PADDLE_FLASH_MILLIS,
+  POWER_UP_FADE_MILLIS,
+  RIGHT_PLAYER_COLOR,
+  TICK_RATE,
   WINDOW_HEIGHT,
   WINDOW_WIDTH
 };
@@ -75,33 +236,56 @@ pub fn launch() -> Result<(), String> {
 
   let _gl_context = window.gl_create_context()?;
   gl::load_with(|procname| video_subsystem.gl_get_proc_address(procname) as *const gl::types::GLvoid);
+  let is_vsync_enabled = video_subsystem.gl_set_swap_interval(SwapInterval::VSync).is_ok();
 
   let mut is_running = true;
   
-  let ball_quad = Quad::textured(16, 16);
-  let paddle_quad = Quad::textured(16, 128);
-
-  let ball_texture = Texture::load(Path::new("res/textures/ball.png"))?;
-  let paddle_texture = Texture::load(Path::new("res/textures/paddle.png"))?;
-  let power_up_texture = Texture::load(Path::new("res/textures/power_up.png"))?;
+  let mut sprite_batch = SpriteBatch::new(256)?;
+
+  let mut atlas_builder = TextureAtlasBuilder::new(256);
+  atlas_builder.load_image("ball", Path::new("res/textures/ball.png"))?;
+  atlas_builder.load_image("paddle", Path::new("res/textures/paddle.png"))?;
+  atlas_builder.load_image("power_up_sheet", Path::new("res/textures/power_up_sheet.png"))?;
+  atlas_builder.load_image("font", Path::new("res/fonts/font.png"))?;
+
+  let texture_atlas = atlas_builder.build()?;
+  let ball_sprite = texture_atlas.sprite("ball").ok_or("Missing ball sprite")?;
+  let paddle_sprite = texture_atlas.sprite("paddle").ok_or("Missing paddle sprite")?;
+  let power_up_sheet = texture_atlas.sprite("power_up_sheet").ok_or("Missing power up sheet")?;
+  let font = BitmapFont::grid(texture_atlas.sprite("font").ok_or("Missing font")?, 16, 6, ' ');
+  let left_score_style = TextStyle::new(TextAlignment::Center, 4.0, LEFT_PLAYER_COLOR);
+  let right_score_style = TextStyle::new(TextAlignment::Center, 4.0, RIGHT_PLAYER_COLOR);
+  let banner_style = TextStyle::new(TextAlignment::Center, 2.0, Color::YELLOW);
+
+  let mut power_up_animation = AnimationPlayer::default();
+  power_up_animation.add("pulse", Animation::from_sheet(power_up_sheet, 4, 1, 120.0, AnimationMode::PingPong));
+  power_up_animation.play("pulse")?;
 
   let shader_program = ShaderProgram::link(
     vec![
       Shader::vertex(Path::new("res/shaders/vertex_shader.glsl"))?,
       Shader::fragment(Path::new("res/shaders/fragment_shader.glsl"))?
     ]
-  );
+  )?;
+
+  let shape_shader_program = ShaderProgram::link(
+    vec![
+      Shader::vertex(Path::new("res/shaders/shape_vertex_shader.glsl"))?,
+      Shader::fragment(Path::new("res/shaders/shape_fragment_shader.glsl"))?
+    ]
+  )?;
+
+  let mut shape_renderer = ShapeRenderer::new(256)?;
+  let mut debug_overlay = DebugOverlay::default();
   
-  let mut power_up_locations = vec![
-    Location::new(WINDOW_WIDTH as f32 / 4.0, (WINDOW_HEIGHT as f32 / 4.0) * 3.0),
-    Location::new((WINDOW_WIDTH as f32 / 4.0) * 3.0, (WINDOW_HEIGHT as f32 / 4.0) * 3.0),
-    Location::new((WINDOW_WIDTH as f32 / 4.0) * 3.0, WINDOW_HEIGHT as f32 / 4.0),
-    Location::new(WINDOW_WIDTH as f32 / 4.0, WINDOW_HEIGHT as f32 / 4.0)
-  ];
+  let seed = SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_nanos() as u64)
+    .unwrap_or_default();
 
-  let mut ball_location = Location::new(WINDOW_WIDTH as f32 / 2.0, WINDOW_HEIGHT as f32 / 2.0);
-  let mut left_paddle_location = Location::new(32.0, WINDOW_HEIGHT as f32 / 2.0);
-  let mut right_paddle_location = Location::new(WINDOW_WIDTH as f32 - 32.0, WINDOW_HEIGHT as f32 / 2.0);
+  let mut simulation = Simulation::with_seed(WINDOW_WIDTH as f32, WINDOW_HEIGHT as f32, MatchRules::default(), seed);
+  let mut inputs = Inputs::default();
+  let mut fixed_timestep = FixedTimestep::new(TICK_RATE)?;
 
   let view_matrix = Matrix4::identity();
   let projection_matrix = Matrix4::orthographic(0.0, WINDOW_WIDTH as f32, WINDOW_HEIGHT as f32, 0.0, -1.0, 1.0);
@@ -109,78 +293,52 @@ pub fn launch() -> Result<(), String> {
   unsafe {
     gl::Viewport(0, 0, WINDOW_WIDTH as i32, WINDOW_HEIGHT as i32);
     gl::ClearColor(0.2, 0.2, 0.4, 1.0);
-    gl::UseProgram(shader_program.id());
 
     gl::Enable(gl::BLEND);
     gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
   }
 
-  set_view_matrix(&shader_program, &view_matrix)?;
-  set_projection_matrix(&shader_program, &projection_matrix)?;
-
-  let ball_speed = 0.5;
-  let mut ball_velocity = Vector2::new(0.5, 0.5);
-  let mut left_paddle_velocity = Vector2::new(0.0, 0.0);
-  let mut right_paddle_velocity = Vector2::new(0.0, 0.0);
-  
-  let barrier_thickness = 8.0;
-  
-  let mut colliders = vec![];
+  shader_program.bind();
+  shader_program.set_sampler(c"tex", 0)?;
 
-  let mut power_up_collider_indices = vec![];
-  for power_up_location in &power_up_locations {
-    power_up_collider_indices.push(colliders.len());
-    colliders.push(Collider::new(power_up_location.x(), power_up_location.y(), 16.0, 16.0));
+  for program in [&shader_program, &shape_shader_program] {
+    program.bind();
+    set_view_matrix(program, &view_matrix)?;
+    set_projection_matrix(program, &projection_matrix)?;
   }
 
-  let ball_collider_index = colliders.len();
-  colliders.push(Collider::new(ball_location.x(), ball_location.y(), 16.0, 16.0));
-
-  let left_paddle_collider_index = colliders.len();
-  colliders.push(Collider::new(left_paddle_location.x(), left_paddle_location.y(), 16.0, 128.0));
-
-  let right_paddle_collider_index = colliders.len();
-  colliders.push(Collider::new(right_paddle_location.x(), right_paddle_location.y(), 16.0, 128.0));
-  
-  let left_barrier_collider_index = colliders.len();
-  colliders.push(Collider::new(0.0, (WINDOW_HEIGHT / 2) as f32, barrier_thickness, WINDOW_HEIGHT as f32));
-  
-  let right_barrier_collider_index = colliders.len();
-  colliders.push(Collider::new(WINDOW_WIDTH as f32, (WINDOW_HEIGHT / 2) as f32, barrier_thickness, WINDOW_HEIGHT as f32));
-  
-  let top_barrier_collider_index = colliders.len();
-  colliders.push(Collider::new((WINDOW_WIDTH / 2) as f32, 0.0, WINDOW_WIDTH as f32, barrier_thickness));
-  
-  let bottom_barrier_collider_index = colliders.len();
-  colliders.push(Collider::new((WINDOW_WIDTH / 2) as f32, WINDOW_HEIGHT as f32, WINDOW_WIDTH as f32, barrier_thickness));
+  let mut left_paddle_flash = 0.0;
+  let mut right_paddle_flash = 0.0;
+  let mut power_up_fade = 0.0;
 
-  let mut current_time = Instant::now();
-  let mut previous_time = current_time;
+  let mut previous_time = Instant::now();
   while is_running {
-    current_time = Instant::now();
-    let deltatime = current_time - previous_time;
+    let current_time = Instant::now();
+    let frame_duration = current_time - previous_time;
     previous_time = current_time;
-    let deltamillis = deltatime.as_millis() as f32;
 
     for event in event_pump.poll_iter() {
       match event {
         Event::Quit { .. } => is_running = false,
 
-        Event::KeyDown { keycode: Some(keycode), repeat, .. } => {
-          if !repeat {
-            match keycode {
-              Keycode::W => left_paddle_velocity.y += -0.5,
-              Keycode::S => left_paddle_velocity.y += 0.5,
-  
-              _ => {}
-            }
+        Event::KeyDown { keycode: Some(keycode), .. } => {
+          match keycode {
+            Keycode::W => inputs.left_paddle_up = true,
+            Keycode::S => inputs.left_paddle_down = true,
+            Keycode::Space if simulation.scoreboard().is_match_over() => {
+              simulation.restart_match();
+              power_up_fade = 0.0;
+            },
+            Keycode::F1 => debug_overlay.toggle(),
+
+            _ => {}
           }
         },
 
         Event::KeyUp { keycode: Some(keycode), .. } => {
           match keycode {
-            Keycode::W => left_paddle_velocity.y -= -0.5,
-            Keycode::S => left_paddle_velocity.y -= 0.5,
+            Keycode::W => inputs.left_paddle_up = false,
+            Keycode::S => inputs.left_paddle_down = false,
 
             _ => {}
           }
@@ -189,171 +347,103 @@ pub fn launch() -> Result<(), String> {
         _ => {}
       }
     }
-    
-    if ball_velocity.x < 0.0 {
-      right_paddle_velocity.y = 0.0;
-    } else {
-      if right_paddle_location.y() < ball_location.y() {
-        right_paddle_velocity.y = 0.5;
-      }
-
-      if right_paddle_location.y() > ball_location.y() {
-        right_paddle_velocity.y = -0.5;
-      }
-    }
-
-    let ball_translation = ball_velocity.normalized() * ball_speed * deltamillis;
-    ball_location.translate(ball_translation);
-
-    let left_paddle_translation = left_paddle_velocity * deltamillis;
-    left_paddle_location.translate(left_paddle_translation);
 
-    let right_paddle_translation = right_paddle_velocity * deltamillis;
-    right_paddle_location.translate(right_paddle_translation);
+    for _ in 0..fixed_timestep.advance(frame_duration) {
+      let tick_millis = fixed_timestep.tick_millis();
 
-    colliders[ball_collider_index].set_location(&ball_location);
-    colliders[left_paddle_collider_index].set_location(&left_paddle_location);
-    colliders[right_paddle_collider_index].set_location(&right_paddle_location);
+      for event in simulation.step(tick_millis, &inputs) {
+        match event {
+          MatchEvent::BallReturned { player: Player::Left } => left_paddle_flash = PADDLE_FLASH_MILLIS,
+          MatchEvent::BallReturned { player: Player::Right } => right_paddle_flash = PADDLE_FLASH_MILLIS,
 
-    let collisions = find_collisions(&colliders);
-    if let Some(collisions) = collisions {
-      for collision in collisions {
-        if collision.primary_index() == ball_collider_index {
-          if collision.secondary_index() == left_barrier_collider_index {
-            ball_location.translate(Vector2::new(collision.penetration_depth(), 0.0));
-            ball_velocity.x *= -1.0;
-          }
-          
-          if collision.secondary_index() == right_barrier_collider_index {
-            ball_location.translate(Vector2::new(-collision.penetration_depth(), 0.0));
-            ball_velocity.x *= -1.0;
-          }
-          
-          if collision.secondary_index() == top_barrier_collider_index {
-            ball_location.translate(Vector2::new(0.0, collision.penetration_depth()));
-            ball_velocity.y *= -1.0;
-          }
-          
-          if collision.secondary_index() == bottom_barrier_collider_index {
-            ball_location.translate(Vector2::new(0.0, -collision.penetration_depth()));
-            ball_velocity.y *= -1.0;
-          }
-
-          if collision.secondary_index() == left_paddle_collider_index {
-            match collision.entry_direction() {
-              CollisionDirection::Left => {
-                ball_location.translate(Vector2::new(-collision.penetration_depth(), 0.0));
-                ball_velocity.x *= -1.0;
-              },
-
-              CollisionDirection::Right => {
-                ball_location.translate(Vector2::new(collision.penetration_depth(), 0.0));
-
-                let signed_offset = left_paddle_location.y() - ball_location.y();
-                let unsigned_offset = if signed_offset > 0.0 { signed_offset } else { signed_offset * -1.0 };
-                let ratio = unsigned_offset / 64.0;
-
-                let final_y = ratio / 2.0;
-                let final_x = 0.5 - final_y;
-
-                ball_velocity.x = final_x;
-                ball_velocity.y = if signed_offset > 0.0 { final_y * -1.0 } else { final_y };
-              },
-
-              CollisionDirection::Top => {
-                ball_location.translate(Vector2::new(0.0, -collision.penetration_depth()));
-                ball_velocity.y *= -1.0;
-                ball_velocity.x = 0.5;
-              },
-
-              CollisionDirection::Bottom => {
-                ball_location.translate(Vector2::new(0.0, collision.penetration_depth()));
-                ball_velocity.y *= -1.0;
-                ball_velocity.x = 0.5;
-              }
-            }
-          }
-
-          if collision.secondary_index() == right_paddle_collider_index {
-            match collision.entry_direction() {
-              CollisionDirection::Left => {
-                ball_location.translate(Vector2::new(-collision.penetration_depth(), 0.0));
-                ball_velocity.x *= -1.0;
-              },
-
-              CollisionDirection::Right => {
-                ball_location.translate(Vector2::new(collision.penetration_depth(), 0.0));
-                ball_velocity.x *= -1.0;
-              },
-
-              CollisionDirection::Top => {
-                ball_location.translate(Vector2::new(0.0, -collision.penetration_depth()));
-                ball_velocity.y *= -1.0;
-              },
-
-              CollisionDirection::Bottom => {
-                ball_location.translate(Vector2::new(0.0, collision.penetration_depth()));
-                ball_velocity.y *= -1.0;
-              }
-            }
-          }
+          _ => {}
+        }
+      }
 
-          for power_up_collider_index in &power_up_collider_indices {
-            if collision.secondary_index() == *power_up_collider_index {
-              let index = collision.secondary_index();
+      power_up_animation.advance(tick_millis);
+      left_paddle_flash = f32::max(left_paddle_flash - tick_millis, 0.0);
+      right_paddle_flash = f32::max(right_paddle_flash - tick_millis, 0.0);
+      power_up_fade = f32::min(power_up_fade + tick_millis, POWER_UP_FADE_MILLIS);
+    }
 
-              power_up_locations[index].translate(
-                Vector2::new(-(WINDOW_WIDTH as f32), -(WINDOW_HEIGHT as f32))
-              );
+    let alpha = fixed_timestep.alpha();
 
-              colliders[index].set_location(&power_up_locations[index]);
-            }
-          }
-        }
+    unsafe {
+      gl::Clear(gl::COLOR_BUFFER_BIT);
+    }
 
-        if collision.primary_index() == left_paddle_collider_index {
-          if collision.secondary_index() == top_barrier_collider_index {
-            left_paddle_location.translate(Vector2::new(0.0, collision.penetration_depth()));
-          }
+    for net_segment in 0..(WINDOW_HEIGHT / NET_SPACING) {
+      let segment_center = Vector2::new(WINDOW_WIDTH as f32 / 2.0, (net_segment * NET_SPACING) as f32 + (NET_SPACING as f32 / 2.0));
+      shape_renderer.fill_rect(Rect::from_center(segment_center, 4.0, NET_SPACING as f32 / 2.0), Color::WHITE.with_alpha(0.5));
+    }
 
-          if collision.secondary_index() == bottom_barrier_collider_index {
-            left_paddle_location.translate(Vector2::new(0.0, -collision.penetration_depth()));
-          }
-        }
+    shape_renderer.flush(&shape_shader_program)?;
 
-        if collision.primary_index() == right_paddle_collider_index {
-          if collision.secondary_index() == top_barrier_collider_index {
-            right_paddle_location.translate(Vector2::new(0.0, collision.penetration_depth()));
-          }
+    if let Some(power_up_sprite) = power_up_animation.sprite() {
+      let power_up_color = Color::WHITE.with_alpha(power_up_fade / POWER_UP_FADE_MILLIS);
 
-          if collision.secondary_index() == bottom_barrier_collider_index {
-            right_paddle_location.translate(Vector2::new(0.0, -collision.penetration_depth()));
-          }
-        }
+      for power_up_transform in simulation.power_up_transforms() {
+        sprite_batch.draw_colored(texture_atlas.texture(), &power_up_sprite, power_up_transform, power_up_color, 0);
       }
     }
 
-    unsafe {
-      gl::Clear(gl::COLOR_BUFFER_BIT);
+    let left_paddle_color = if left_paddle_flash > 0.0 { Color::WHITE } else { LEFT_PLAYER_COLOR };
+    let right_paddle_color = if right_paddle_flash > 0.0 { Color::WHITE } else { RIGHT_PLAYER_COLOR };
+
+    sprite_batch.draw(texture_atlas.texture(), &ball_sprite, &simulation.interpolated_ball_transform(alpha), 1);
+    sprite_batch.draw_colored(texture_atlas.texture(), &paddle_sprite, &simulation.interpolated_left_paddle_transform(alpha), left_paddle_color, 1);
+    sprite_batch.draw_colored(texture_atlas.texture(), &paddle_sprite, &simulation.interpolated_right_paddle_transform(alpha), right_paddle_color, 1);
+
+    let scoreboard = simulation.scoreboard();
+    let playfield_tint = if scoreboard.is_match_over() { MATCH_OVER_TINT } else { Color::WHITE };
+
+    sprite_batch.flush_tinted(&shader_program, playfield_tint)?;
+
+    sprite_batch.draw_text(
+      texture_atlas.texture(),
+      &font,
+      &scoreboard.score(Player::Left).to_string(),
+      Vector2::new(WINDOW_WIDTH as f32 / 4.0, 24.0),
+      &left_score_style,
+      2
+    );
+
+    sprite_batch.draw_text(
+      texture_atlas.texture(),
+      &font,
+      &scoreboard.score(Player::Right).to_string(),
+      Vector2::new(WINDOW_WIDTH as f32 * 3.0 / 4.0, 24.0),
+      &right_score_style,
+      2
+    );
+
+    if let Some(winner) = scoreboard.winner() {
+      let banner = match winner {
+        Player::Left => "LEFT PLAYER WINS\nPRESS SPACE",
+        Player::Right => "RIGHT PLAYER WINS\nPRESS SPACE"
+      };
+
+      sprite_batch.draw_text(
+        texture_atlas.texture(),
+        &font,
+        banner,
+        Vector2::new(WINDOW_WIDTH as f32 / 2.0, WINDOW_HEIGHT as f32 / 2.0),
+        &banner_style,
+        2
+      );
     }
 
-    for power_up_location in &power_up_locations {
-      set_model_matrix(&shader_program, power_up_location.matrix())?;
-      render_textured_quad(&ball_quad, &power_up_texture);
-    }
+    sprite_batch.flush(&shader_program)?;
 
-    set_model_matrix(&shader_program, ball_location.matrix())?;
-    render_textured_quad(&ball_quad, &ball_texture);
-    
-    set_model_matrix(&shader_program, left_paddle_location.matrix())?;
-    render_textured_quad(&paddle_quad, &paddle_texture);
-    
-    set_model_matrix(&shader_program, right_paddle_location.matrix())?;
-    render_textured_quad(&paddle_quad, &paddle_texture);
+    debug_overlay.draw(&simulation, &mut shape_renderer);
+    shape_renderer.flush(&shape_shader_program)?;
 
+    simulation.drain_collision_events();
     window.gl_swap_window();
-    
-    limit_frame_rate(previous_time, 60);
+
+    if !is_vsync_enabled {
+      limit_frame_rate(current_time, FALLBACK_FRAME_RATE);
+    }
   }
 
   Ok(())
@@ -0,0 +1,9 @@
+use crate::prelude::Player;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum MatchEvent {
+  PointScored { scorer: Player, left_score: u32, right_score: u32 },
+  BallServed { receiver: Player },
+  BallReturned { player: Player },
+  MatchWon { winner: Player }
+}
\ No newline at end of file
@@ -0,0 +1,20 @@
+use crate::prelude::ServeDirection;
+
+#[derive(Clone, Copy)]
+pub struct MatchRules {
+  pub target_score: u32,
+  pub win_by: u32,
+  pub serve_delay: f32,
+  pub serve_direction: ServeDirection
+}
+
+impl Default for MatchRules {
+  fn default() -> Self {
+    Self {
+      target_score: 11,
+      win_by: 2,
+      serve_delay: 1000.0,
+      serve_direction: ServeDirection::TowardsConceder
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,184 @@
+use std::ops::Mul;
+
+use crate::prelude::{Matrix4, Vector2, Vector3, Vector4};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Matrix3 {
+  pub x: Vector3,
+  pub y: Vector3,
+  pub z: Vector3
+}
+
+impl Matrix3 {
+  pub fn identity() -> Self {
+    Self {
+      x: Vector3::new(1.0, 0.0, 0.0),
+      y: Vector3::new(0.0, 1.0, 0.0),
+      z: Vector3::new(0.0, 0.0, 1.0)
+    }
+  }
+
+  pub fn translation(x: f32, y: f32) -> Self {
+    Self {
+      x: Vector3::new(1.0, 0.0, x),
+      y: Vector3::new(0.0, 1.0, y),
+      z: Vector3::new(0.0, 0.0, 1.0)
+    }
+  }
+
+  pub fn rotation(angle: f32) -> Self {
+    let (sine, cosine) = angle.sin_cos();
+
+    Self {
+      x: Vector3::new(cosine, -sine, 0.0),
+      y: Vector3::new(sine, cosine, 0.0),
+      z: Vector3::new(0.0, 0.0, 1.0)
+    }
+  }
+
+  pub fn scale(x: f32, y: f32) -> Self {
+    Self {
+      x: Vector3::new(x, 0.0, 0.0),
+      y: Vector3::new(0.0, y, 0.0),
+      z: Vector3::new(0.0, 0.0, 1.0)
+    }
+  }
+
+  pub fn column(&self, index: usize) -> Vector3 {
+    match index {
+      0 => Vector3::new(self.x.x, self.y.x, self.z.x),
+      1 => Vector3::new(self.x.y, self.y.y, self.z.y),
+      2 => Vector3::new(self.x.z, self.y.z, self.z.z),
+      _ => panic!("Matrix3 column index {index} is out of range")
+    }
+  }
+
+  pub fn transpose(&self) -> Self {
+    Self {
+      x: self.column(0),
+      y: self.column(1),
+      z: self.column(2)
+    }
+  }
+
+  pub fn transform_point(&self, point: Vector2) -> Vector2 {
+    let transformed = *self * Vector3::new(point.x, point.y, 1.0);
+
+    Vector2::new(transformed.x, transformed.y)
+  }
+
+  pub fn transform_vector(&self, vector: Vector2) -> Vector2 {
+    let transformed = *self * Vector3::new(vector.x, vector.y, 0.0);
+
+    Vector2::new(transformed.x, transformed.y)
+  }
+}
+
+impl Mul for Matrix3 {
+  type Output = Self;
+
+  fn mul(self, rhs: Self) -> Self::Output {
+    let columns = [rhs.column(0), rhs.column(1), rhs.column(2)];
+    let multiply_row = |row: Vector3| Vector3::new(row.dot(columns[0]), row.dot(columns[1]), row.dot(columns[2]));
+
+    Self {
+      x: multiply_row(self.x),
+      y: multiply_row(self.y),
+      z: multiply_row(self.z)
+    }
+  }
+}
+
+impl Mul<Vector3> for Matrix3 {
+  type Output = Vector3;
+
+  fn mul(self, rhs: Vector3) -> Self::Output {
+    Vector3::new(self.x.dot(rhs), self.y.dot(rhs), self.z.dot(rhs))
+  }
+}
+
+impl From<Matrix3> for Matrix4 {
+  fn from(matrix: Matrix3) -> Self {
+    Self {
+      x: Vector4::new(matrix.x.x, matrix.x.y, 0.0, matrix.x.z),
+      y: Vector4::new(matrix.y.x, matrix.y.y, 0.0, matrix.y.z),
+      z: Vector4::new(0.0, 0.0, 1.0, 0.0),
+      w: Vector4::new(matrix.z.x, matrix.z.y, 0.0, matrix.z.z)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn identity_constructor() {
+    let mat3 = Matrix3::identity();
+
+    assert_eq!(mat3.x, Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(mat3.y, Vector3::new(0.0, 1.0, 0.0));
+    assert_eq!(mat3.z, Vector3::new(0.0, 0.0, 1.0));
+  }
+
+  #[test]
+  fn translation() {
+    let mat3 = Matrix3::translation(4.0, 8.0);
+
+    assert_eq!(mat3.transform_point(Vector2::new(1.0, 1.0)), Vector2::new(5.0, 9.0));
+    assert_eq!(mat3.transform_vector(Vector2::new(1.0, 1.0)), Vector2::new(1.0, 1.0));
+  }
+
+  #[test]
+  fn rotation() {
+    let rotated = Matrix3::rotation(std::f32::consts::FRAC_PI_2).transform_point(Vector2::new(1.0, 0.0));
+
+    assert!(rotated.x.abs() < 0.0001);
+    assert!((rotated.y - 1.0).abs() < 0.0001);
+  }
+
+  #[test]
+  fn composition() {
+    let mat3 = Matrix3::translation(10.0, 0.0) * Matrix3::scale(2.0, 3.0);
+
+    assert_eq!(mat3.transform_point(Vector2::new(1.0, 1.0)), Vector2::new(12.0, 3.0));
+    assert_eq!(Matrix3::identity() * mat3, mat3);
+  }
+
+  #[test]
+  fn transpose() {
+    let mat3 = Matrix3::translation(4.0, 8.0);
+
+    assert_eq!(mat3.transpose().z, Vector3::new(4.0, 8.0, 1.0));
+    assert_eq!(mat3.transpose().transpose(), mat3);
+  }
+
+  #[test]
+  fn columns() {
+    let mat3 = Matrix3::translation(4.0, 8.0);
+
+    assert_eq!(mat3.column(0), Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(mat3.column(2), Vector3::new(4.0, 8.0, 1.0));
+  }
+
+  #[test]
+  #[should_panic]
+  fn column_out_of_range() {
+    Matrix3::identity().column(3);
+  }
+
+  #[test]
+  fn matrix_4_conversion() {
+    let mat3 = Matrix3::translation(16.0, -8.0) * Matrix3::rotation(0.5) * Matrix3::scale(2.0, 4.0);
+    let mat4 = Matrix4::translation(16.0, -8.0, 0.0) * Matrix4::rotation_z(0.5) * Matrix4::scale(2.0, 4.0, 1.0);
+
+    let converted = Matrix4::from(mat3).to_column_major();
+    let expected = mat4.to_column_major();
+
+    for index in 0..16 {
+      assert!((converted[index] - expected[index]).abs() < 0.0001);
+    }
+
+    assert_eq!(Matrix4::from(Matrix3::identity()), Matrix4::identity());
+  }
+}
\ No newline at end of file
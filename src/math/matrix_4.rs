@@ -1,6 +1,8 @@
+use std::ops::Mul;
+
 use crate::prelude::Vector4;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub struct Matrix4 {
   pub x: Vector4,
   pub y: Vector4,
@@ -34,8 +36,113 @@ impl Matrix4 {
     }
   }
 
+  pub fn translation(x: f32, y: f32, z: f32) -> Self {
+    Self {
+      x: Vector4::new(1.0, 0.0, 0.0, x),
+      y: Vector4::new(0.0, 1.0, 0.0, y),
+      z: Vector4::new(0.0, 0.0, 1.0, z),
+      w: Vector4::new(0.0, 0.0, 0.0, 1.0)
+    }
+  }
+
+  pub fn rotation_z(angle: f32) -> Self {
+    let (sine, cosine) = angle.sin_cos();
+
+    Self {
+      x: Vector4::new(cosine, -sine, 0.0, 0.0),
+      y: Vector4::new(sine, cosine, 0.0, 0.0),
+      z: Vector4::new(0.0, 0.0, 1.0, 0.0),
+      w: Vector4::new(0.0, 0.0, 0.0, 1.0)
+    }
+  }
+
+  pub fn scale(x: f32, y: f32, z: f32) -> Self {
+    Self {
+      x: Vector4::new(x, 0.0, 0.0, 0.0),
+      y: Vector4::new(0.0, y, 0.0, 0.0),
+      z: Vector4::new(0.0, 0.0, z, 0.0),
+      w: Vector4::new(0.0, 0.0, 0.0, 1.0)
+    }
+  }
+
+  pub fn column(&self, index: usize) -> Vector4 {
+    let rows = self.rows();
+
+    Vector4::new(rows[0][index], rows[1][index], rows[2][index], rows[3][index])
+  }
+
+  pub fn transpose(&self) -> Self {
+    Self {
+      x: self.column(0),
+      y: self.column(1),
+      z: self.column(2),
+      w: self.column(3)
+    }
+  }
+
+  pub fn inverse(&self) -> Option<Self> {
+    let mut rows = self.rows();
+    let mut inverse = Matrix4::identity().rows();
+
+    let magnitude = rows.iter().flatten().fold(0.0_f32, |magnitude, value| magnitude.max(value.abs()));
+    let tolerance = magnitude * f32::EPSILON * 4.0;
+
+    for column in 0..4 {
+      let pivot = (column..4).max_by(|a, b| rows[*a][column].abs().total_cmp(&rows[*b][column].abs()))?;
+
+      if rows[pivot][column].abs() <= tolerance {
+        return None;
+      }
+
+      rows.swap(column, pivot);
+      inverse.swap(column, pivot);
+
+      let pivot_value = rows[column][column];
+      for index in 0..4 {
+        rows[column][index] /= pivot_value;
+        inverse[column][index] /= pivot_value;
+      }
+
+      for row in 0..4 {
+        if row == column {
+          continue;
+        }
+
+        let factor = rows[row][column];
+        for index in 0..4 {
+          rows[row][index] -= factor * rows[column][index];
+          inverse[row][index] -= factor * inverse[column][index];
+        }
+      }
+    }
+
+    Some(Self::from_rows(inverse))
+  }
+
+  fn rows(&self) -> [[f32; 4]; 4] {
+    [
+      [self.x.x, self.x.y, self.x.z, self.x.w],
+      [self.y.x, self.y.y, self.y.z, self.y.w],
+      [self.z.x, self.z.y, self.z.z, self.z.w],
+      [self.w.x, self.w.y, self.w.z, self.w.w]
+    ]
+  }
+
+  fn from_rows(rows: [[f32; 4]; 4]) -> Self {
+    Self {
+      x: Vector4::new(rows[0][0], rows[0][1], rows[0][2], rows[0][3]),
+      y: Vector4::new(rows[1][0], rows[1][1], rows[1][2], rows[1][3]),
+      z: Vector4::new(rows[2][0], rows[2][1], rows[2][2], rows[2][3]),
+      w: Vector4::new(rows[3][0], rows[3][1], rows[3][2], rows[3][3])
+    }
+  }
+
   pub fn flatten(&self) -> Vec<f32> {
-    vec![
+    self.to_column_major().to_vec()
+  }
+
+  pub fn to_column_major(&self) -> [f32; 16] {
+    [
       self.x.x, self.y.x, self.z.x, self.w.x,
       self.x.y, self.y.y, self.z.y, self.w.y,
       self.x.z, self.y.z, self.z.z, self.w.z,
@@ -44,9 +151,48 @@ impl Matrix4 {
   }
 }
 
+impl Mul for Matrix4 {
+  type Output = Self;
+
+  fn mul(self, rhs: Self) -> Self::Output {
+    let columns = [rhs.column(0), rhs.column(1), rhs.column(2), rhs.column(3)];
+    let multiply_row = |row: Vector4| Vector4::new(
+      row.dot(columns[0]),
+      row.dot(columns[1]),
+      row.dot(columns[2]),
+      row.dot(columns[3])
+    );
+
+    Self {
+      x: multiply_row(self.x),
+      y: multiply_row(self.y),
+      z: multiply_row(self.z),
+      w: multiply_row(self.w)
+    }
+  }
+}
+
+impl Mul<Vector4> for Matrix4 {
+  type Output = Vector4;
+
+  fn mul(self, rhs: Vector4) -> Self::Output {
+    Vector4::new(self.x.dot(rhs), self.y.dot(rhs), self.z.dot(rhs), self.w.dot(rhs))
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::Matrix4;
+  use crate::prelude::Vector4;
+
+  fn assert_matrix_near(actual: Matrix4, expected: Matrix4) {
+    let actual = actual.flatten();
+    let expected = expected.flatten();
+
+    for index in 0..16 {
+      assert!((actual[index] - expected[index]).abs() < 0.0001, "{actual:?} != {expected:?}");
+    }
+  }
 
   #[test]
   fn identity_constructor() {
@@ -135,4 +281,119 @@ mod tests {
     assert_eq!(flattened_matrix[14], 0.0);
     assert_eq!(flattened_matrix[15], 1.0);
   }
+
+  #[test]
+  fn translation_constructor() {
+    let mat4 = Matrix4::translation(4.0, 8.0, 2.0);
+    let point = mat4 * Vector4::new(1.0, 1.0, 1.0, 1.0);
+    let direction = mat4 * Vector4::new(1.0, 1.0, 1.0, 0.0);
+
+    assert_eq!(point, Vector4::new(5.0, 9.0, 3.0, 1.0));
+    assert_eq!(direction, Vector4::new(1.0, 1.0, 1.0, 0.0));
+  }
+
+  #[test]
+  fn rotation_constructor() {
+    let mat4 = Matrix4::rotation_z(std::f32::consts::FRAC_PI_2);
+    let rotated = mat4 * Vector4::new(1.0, 0.0, 0.0, 1.0);
+
+    assert!(rotated.x.abs() < 0.0001);
+    assert!((rotated.y - 1.0).abs() < 0.0001);
+    assert_eq!(rotated.z, 0.0);
+    assert_eq!(rotated.w, 1.0);
+  }
+
+  #[test]
+  fn scale_constructor() {
+    let mat4 = Matrix4::scale(2.0, 3.0, 4.0);
+    let scaled = mat4 * Vector4::new(1.0, 1.0, 1.0, 1.0);
+
+    assert_eq!(scaled, Vector4::new(2.0, 3.0, 4.0, 1.0));
+  }
+
+  #[test]
+  fn multiplication() {
+    let translation = Matrix4::translation(10.0, 0.0, 0.0);
+    let scale = Matrix4::scale(2.0, 2.0, 1.0);
+
+    let scale_then_translate = translation * scale;
+    let translate_then_scale = scale * translation;
+
+    assert_eq!(scale_then_translate * Vector4::new(1.0, 1.0, 0.0, 1.0), Vector4::new(12.0, 2.0, 0.0, 1.0));
+    assert_eq!(translate_then_scale * Vector4::new(1.0, 1.0, 0.0, 1.0), Vector4::new(22.0, 2.0, 0.0, 1.0));
+    assert_eq!(Matrix4::identity() * scale, scale);
+  }
+
+  #[test]
+  fn transpose() {
+    let mat4 = Matrix4::translation(4.0, 8.0, 2.0);
+    let transposed = mat4.transpose();
+
+    assert_eq!(transposed.w, Vector4::new(4.0, 8.0, 2.0, 1.0));
+    assert_eq!(transposed.x.w, 0.0);
+    assert_eq!(transposed.transpose(), mat4);
+  }
+
+  #[test]
+  fn inverse() {
+    let mat4 = Matrix4::translation(16.0, -8.0, 0.0)
+      * Matrix4::rotation_z(0.75)
+      * Matrix4::scale(2.0, 4.0, 1.0);
+
+    let inverse = mat4.inverse().unwrap();
+
+    assert_matrix_near(mat4 * inverse, Matrix4::identity());
+    assert_matrix_near(inverse * mat4, Matrix4::identity());
+  }
+
+  #[test]
+  fn orthographic_unprojection() {
+    let projection = Matrix4::orthographic(0.0, 800.0, 600.0, 0.0, -1.0, 1.0);
+    let unprojected = projection.inverse().unwrap() * Vector4::new(0.0, 0.0, 0.0, 1.0);
+
+    assert!((unprojected.x - 400.0).abs() < 0.001);
+    assert!((unprojected.y - 300.0).abs() < 0.001);
+  }
+
+  #[test]
+  fn singular_inverse() {
+    assert!(Matrix4::scale(1.0, 0.0, 1.0).inverse().is_none());
+    assert!(Matrix4::from_rows([[0.0; 4]; 4]).inverse().is_none());
+  }
+
+  #[test]
+  fn small_scale_inverse() {
+    let mat4 = Matrix4::from_rows([
+      [1e-8, 0.0, 0.0, 0.0],
+      [0.0, 1e-8, 0.0, 0.0],
+      [0.0, 0.0, 1e-8, 0.0],
+      [0.0, 0.0, 0.0, 1e-8]
+    ]);
+
+    let inverse = mat4.inverse().unwrap();
+
+    assert!((inverse.x.x - 1e8).abs() < 1.0);
+  }
+
+  #[test]
+  fn ill_conditioned_inverse() {
+    let mat4 = Matrix4::from_rows([
+      [1e6, 0.0, 0.0, 0.0],
+      [0.0, 1e6, 0.0, 0.0],
+      [0.0, 0.0, 1e6, 0.0],
+      [0.0, 0.0, 0.0, 1e-2]
+    ]);
+
+    assert!(mat4.inverse().is_none());
+  }
+
+  #[test]
+  fn column_major() {
+    let matrix = Matrix4::translation(4.0, 8.0, 2.0);
+    let columns = matrix.to_column_major();
+
+    assert_eq!(columns[..12], [1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+    assert_eq!(columns[12..], [4.0, 8.0, 2.0, 1.0]);
+    assert_eq!(columns.to_vec(), matrix.flatten());
+  }
 }
\ No newline at end of file
@@ -1,7 +1,13 @@
 mod vector_2;
+mod vector_3;
 mod vector_4;
+mod matrix_3;
 mod matrix_4;
+mod rect;
 
 pub use vector_2::Vector2;
+pub use vector_3::Vector3;
 pub use vector_4::Vector4;
-pub use matrix_4::Matrix4;
\ No newline at end of file
+pub use matrix_3::Matrix3;
+pub use matrix_4::Matrix4;
+pub use rect::Rect;
\ No newline at end of file
@@ -0,0 +1,192 @@
+use crate::prelude::Vector2;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Rect {
+  min: Vector2,
+  max: Vector2
+}
+
+impl Rect {
+  pub fn from_center(center: Vector2, width: f32, height: f32) -> Self {
+    let half_extents = Vector2::new(width.abs() / 2.0, height.abs() / 2.0);
+
+    Self {
+      min: center - half_extents,
+      max: center + half_extents
+    }
+  }
+
+  pub fn from_corners(first: Vector2, second: Vector2) -> Self {
+    Self {
+      min: Vector2::new(first.x.min(second.x), first.y.min(second.y)),
+      max: Vector2::new(first.x.max(second.x), first.y.max(second.y))
+    }
+  }
+
+  pub fn left(&self) -> f32 {
+    self.min.x
+  }
+
+  pub fn right(&self) -> f32 {
+    self.max.x
+  }
+
+  pub fn top(&self) -> f32 {
+    self.min.y
+  }
+
+  pub fn bottom(&self) -> f32 {
+    self.max.y
+  }
+
+  pub fn min(&self) -> Vector2 {
+    self.min
+  }
+
+  pub fn max(&self) -> Vector2 {
+    self.max
+  }
+
+  pub fn width(&self) -> f32 {
+    self.max.x - self.min.x
+  }
+
+  pub fn height(&self) -> f32 {
+    self.max.y - self.min.y
+  }
+
+  pub fn center(&self) -> Vector2 {
+    self.min.lerp(self.max, 0.5)
+  }
+
+  pub fn contains_point(&self, point: Vector2) -> bool {
+    point.x >= self.min.x
+    && point.x <= self.max.x
+    && point.y >= self.min.y
+    && point.y <= self.max.y
+  }
+
+  pub fn intersects(&self, other: &Rect) -> bool {
+    self.max.x > other.min.x
+    && other.max.x > self.min.x
+    && self.max.y > other.min.y
+    && other.max.y > self.min.y
+  }
+
+  pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+    if !self.intersects(other) {
+      return None;
+    }
+
+    Some(Rect {
+      min: Vector2::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y)),
+      max: Vector2::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y))
+    })
+  }
+
+  pub fn union(&self, other: &Rect) -> Rect {
+    Rect {
+      min: Vector2::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+      max: Vector2::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y))
+    }
+  }
+
+  pub fn expand(&self, amount: f32) -> Rect {
+    let center = self.center();
+
+    Rect::from_center(
+      center,
+      (self.width() + (amount * 2.0)).max(0.0),
+      (self.height() + (amount * 2.0)).max(0.0)
+    )
+  }
+
+  pub fn clamp_point(&self, point: Vector2) -> Vector2 {
+    Vector2::new(
+      point.x.clamp(self.min.x, self.max.x),
+      point.y.clamp(self.min.y, self.max.y)
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn center_constructor() {
+    let rect = Rect::from_center(Vector2::new(32.0, 16.0), 16.0, 8.0);
+
+    assert_eq!(rect.left(), 24.0);
+    assert_eq!(rect.right(), 40.0);
+    assert_eq!(rect.top(), 12.0);
+    assert_eq!(rect.bottom(), 20.0);
+    assert_eq!(rect.width(), 16.0);
+    assert_eq!(rect.height(), 8.0);
+    assert_eq!(rect.center(), Vector2::new(32.0, 16.0));
+  }
+
+  #[test]
+  fn negative_center_constructor() {
+    let rect = Rect::from_center(Vector2::new(32.0, 16.0), -16.0, -8.0);
+
+    assert_eq!(rect, Rect::from_center(Vector2::new(32.0, 16.0), 16.0, 8.0));
+    assert_eq!(rect.clamp_point(Vector2::new(0.0, 0.0)), Vector2::new(24.0, 12.0));
+  }
+
+  #[test]
+  fn corner_constructor() {
+    let rect = Rect::from_corners(Vector2::new(10.0, 2.0), Vector2::new(4.0, 8.0));
+
+    assert_eq!(rect.min(), Vector2::new(4.0, 2.0));
+    assert_eq!(rect.max(), Vector2::new(10.0, 8.0));
+  }
+
+  #[test]
+  fn point_containment() {
+    let rect = Rect::from_corners(Vector2::new(0.0, 0.0), Vector2::new(10.0, 10.0));
+
+    assert!(rect.contains_point(Vector2::new(5.0, 5.0)));
+    assert!(rect.contains_point(Vector2::new(10.0, 0.0)));
+    assert!(!rect.contains_point(Vector2::new(10.5, 5.0)));
+    assert!(!rect.contains_point(Vector2::new(5.0, -0.5)));
+  }
+
+  #[test]
+  fn intersection() {
+    let first = Rect::from_corners(Vector2::new(0.0, 0.0), Vector2::new(10.0, 10.0));
+    let second = Rect::from_corners(Vector2::new(5.0, 8.0), Vector2::new(15.0, 20.0));
+    let touching = Rect::from_corners(Vector2::new(10.0, 0.0), Vector2::new(20.0, 10.0));
+
+    assert!(first.intersects(&second));
+    assert_eq!(first.intersection(&second), Some(Rect::from_corners(Vector2::new(5.0, 8.0), Vector2::new(10.0, 10.0))));
+
+    assert!(!first.intersects(&touching));
+    assert_eq!(first.intersection(&touching), None);
+  }
+
+  #[test]
+  fn union() {
+    let first = Rect::from_corners(Vector2::new(0.0, 0.0), Vector2::new(10.0, 10.0));
+    let second = Rect::from_corners(Vector2::new(20.0, -5.0), Vector2::new(25.0, 5.0));
+
+    assert_eq!(first.union(&second), Rect::from_corners(Vector2::new(0.0, -5.0), Vector2::new(25.0, 10.0)));
+  }
+
+  #[test]
+  fn expansion() {
+    let rect = Rect::from_center(Vector2::new(0.0, 0.0), 10.0, 4.0);
+
+    assert_eq!(rect.expand(2.0), Rect::from_center(Vector2::new(0.0, 0.0), 14.0, 8.0));
+    assert_eq!(rect.expand(-3.0), Rect::from_center(Vector2::new(0.0, 0.0), 4.0, 0.0));
+  }
+
+  #[test]
+  fn point_clamping() {
+    let rect = Rect::from_corners(Vector2::new(0.0, 0.0), Vector2::new(10.0, 10.0));
+
+    assert_eq!(rect.clamp_point(Vector2::new(-5.0, 5.0)), Vector2::new(0.0, 5.0));
+    assert_eq!(rect.clamp_point(Vector2::new(12.0, 20.0)), Vector2::new(10.0, 10.0));
+    assert_eq!(rect.clamp_point(Vector2::new(3.0, 4.0)), Vector2::new(3.0, 4.0));
+  }
+}
\ No newline at end of file
@@ -1,25 +1,130 @@
-use std::ops::Mul;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub struct Vector2 {
   pub x: f32,
   pub y: f32
 }
 
 impl Vector2 {
+  pub const ZERO: Self = Self { x: 0.0, y: 0.0 };
+
   pub fn new(x: f32, y: f32) -> Self {
     Self { x, y }
   }
 
   pub fn normalized(&self) -> Self {
+    let length = self.length();
+
+    if length == 0.0 {
+      return Self::ZERO;
+    }
+
+    Self {
+      x: self.x / length,
+      y: self.y / length
+    }
+  }
+
+  pub fn length(&self) -> f32 {
+    f32::sqrt(self.length_squared())
+  }
+
+  pub fn length_squared(&self) -> f32 {
+    (self.x * self.x) + (self.y * self.y)
+  }
+
+  pub fn dot(&self, other: Vector2) -> f32 {
+    (self.x * other.x) + (self.y * other.y)
+  }
+
+  pub fn perp(&self) -> Self {
+    Self {
+      x: -self.y,
+      y: self.x
+    }
+  }
+
+  pub fn reflect(&self, normal: Vector2) -> Self {
+    *self - (normal * (2.0 * self.dot(normal)))
+  }
+
+  pub fn lerp(&self, target: Vector2, alpha: f32) -> Self {
+    *self + ((target - *self) * alpha)
+  }
+
+  pub fn distance(&self, other: Vector2) -> f32 {
+    (other - *self).length()
+  }
+
+  pub fn angle(&self) -> f32 {
+    self.y.atan2(self.x)
+  }
+
+  pub fn rotate(&self, angle: f32) -> Self {
+    let (sine, cosine) = angle.sin_cos();
+
+    Self {
+      x: (self.x * cosine) - (self.y * sine),
+      y: (self.x * sine) + (self.y * cosine)
+    }
+  }
+
+  pub fn clamp_length(&self, max_length: f32) -> Self {
+    let max_length = max_length.max(0.0);
+
+    if self.length_squared() <= max_length * max_length {
+      return *self;
+    }
+
+    self.normalized() * max_length
+  }
+}
+
+impl Add for Vector2 {
+  type Output = Self;
+
+  fn add(self, rhs: Self) -> Self::Output {
     Self {
-      x: self.x / self.length(),
-      y: self.y / self.length()
+      x: self.x + rhs.x,
+      y: self.y + rhs.y
     }
   }
+}
+
+impl AddAssign for Vector2 {
+  fn add_assign(&mut self, rhs: Self) {
+    self.x += rhs.x;
+    self.y += rhs.y;
+  }
+}
 
-  fn length(&self) -> f32 {
-    f32::sqrt((self.x * self.x) + (self.y * self.y))
+impl Sub for Vector2 {
+  type Output = Self;
+
+  fn sub(self, rhs: Self) -> Self::Output {
+    Self {
+      x: self.x - rhs.x,
+      y: self.y - rhs.y
+    }
+  }
+}
+
+impl SubAssign for Vector2 {
+  fn sub_assign(&mut self, rhs: Self) {
+    self.x -= rhs.x;
+    self.y -= rhs.y;
+  }
+}
+
+impl Neg for Vector2 {
+  type Output = Self;
+
+  fn neg(self) -> Self::Output {
+    Self {
+      x: -self.x,
+      y: -self.y
+    }
   }
 }
 
@@ -34,6 +139,24 @@ impl Mul<f32> for Vector2 {
   }
 }
 
+impl MulAssign<f32> for Vector2 {
+  fn mul_assign(&mut self, rhs: f32) {
+    self.x *= rhs;
+    self.y *= rhs;
+  }
+}
+
+impl Div<f32> for Vector2 {
+  type Output = Self;
+
+  fn div(self, rhs: f32) -> Self::Output {
+    Self {
+      x: self.x / rhs,
+      y: self.y / rhs
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -99,4 +222,120 @@ mod tests {
 
     assert_eq!(output_vector.length(), expected_output_length);
   }
+
+  #[test]
+  fn zero_normalization() {
+    let output_vector = Vector2::ZERO.normalized();
+
+    assert_eq!(output_vector, Vector2::ZERO);
+  }
+
+  #[test]
+  fn length_squared() {
+    let vector = Vector2::new(3.0, 4.0);
+
+    assert_eq!(vector.length_squared(), 25.0);
+    assert_eq!(vector.length(), 5.0);
+  }
+
+  #[test]
+  fn addition() {
+    let mut vector = Vector2::new(1.0, 2.0) + Vector2::new(3.0, 5.0);
+    assert_eq!(vector, Vector2::new(4.0, 7.0));
+
+    vector += Vector2::new(1.0, 1.0);
+    assert_eq!(vector, Vector2::new(5.0, 8.0));
+  }
+
+  #[test]
+  fn subtraction() {
+    let mut vector = Vector2::new(4.0, 7.0) - Vector2::new(3.0, 5.0);
+    assert_eq!(vector, Vector2::new(1.0, 2.0));
+
+    vector -= Vector2::new(1.0, 1.0);
+    assert_eq!(vector, Vector2::new(0.0, 1.0));
+  }
+
+  #[test]
+  fn negation() {
+    assert_eq!(-Vector2::new(1.0, -2.0), Vector2::new(-1.0, 2.0));
+  }
+
+  #[test]
+  fn scaling() {
+    let mut vector = Vector2::new(2.0, 4.0) / 2.0;
+    assert_eq!(vector, Vector2::new(1.0, 2.0));
+
+    vector *= 3.0;
+    assert_eq!(vector, Vector2::new(3.0, 6.0));
+  }
+
+  #[test]
+  fn dot_product() {
+    assert_eq!(Vector2::new(1.0, 2.0).dot(Vector2::new(3.0, 4.0)), 11.0);
+    assert_eq!(Vector2::new(1.0, 0.0).dot(Vector2::new(0.0, 1.0)), 0.0);
+  }
+
+  #[test]
+  fn perpendicular() {
+    let vector = Vector2::new(2.0, 3.0);
+
+    assert_eq!(vector.perp(), Vector2::new(-3.0, 2.0));
+    assert_eq!(vector.dot(vector.perp()), 0.0);
+  }
+
+  #[test]
+  fn reflection() {
+    let vector = Vector2::new(1.0, 1.0);
+
+    assert_eq!(vector.reflect(Vector2::new(0.0, -1.0)), Vector2::new(1.0, -1.0));
+    assert_eq!(vector.reflect(Vector2::new(-1.0, 0.0)), Vector2::new(-1.0, 1.0));
+  }
+
+  #[test]
+  fn interpolation() {
+    let start = Vector2::new(0.0, 10.0);
+    let end = Vector2::new(10.0, 20.0);
+
+    assert_eq!(start.lerp(end, 0.0), start);
+    assert_eq!(start.lerp(end, 0.5), Vector2::new(5.0, 15.0));
+    assert_eq!(start.lerp(end, 1.0), end);
+  }
+
+  #[test]
+  fn distance() {
+    assert_eq!(Vector2::new(1.0, 1.0).distance(Vector2::new(4.0, 5.0)), 5.0);
+  }
+
+  #[test]
+  fn angle() {
+    assert_eq!(Vector2::new(1.0, 0.0).angle(), 0.0);
+    assert_eq!(Vector2::new(0.0, 1.0).angle(), std::f32::consts::FRAC_PI_2);
+    assert_eq!(Vector2::new(-1.0, 0.0).angle(), std::f32::consts::PI);
+  }
+
+  #[test]
+  fn rotation() {
+    let rotated_vector = Vector2::new(1.0, 0.0).rotate(std::f32::consts::FRAC_PI_2);
+
+    assert!(rotated_vector.x.abs() < 0.0001);
+    assert!((rotated_vector.y - 1.0).abs() < 0.0001);
+
+    let round_trip = Vector2::new(3.0, 4.0).rotate(1.0).rotate(-1.0);
+
+    assert!((round_trip.x - 3.0).abs() < 0.0001);
+    assert!((round_trip.y - 4.0).abs() < 0.0001);
+  }
+
+  #[test]
+  fn length_clamping() {
+    assert_eq!(Vector2::new(3.0, 4.0).clamp_length(10.0), Vector2::new(3.0, 4.0));
+    assert_eq!(Vector2::new(3.0, 4.0).clamp_length(2.5), Vector2::new(1.5, 2.0));
+    assert_eq!(Vector2::ZERO.clamp_length(1.0), Vector2::ZERO);
+  }
+
+  #[test]
+  fn negative_length_clamping() {
+    assert_eq!(Vector2::new(3.0, 4.0).clamp_length(-2.5), Vector2::ZERO);
+  }
 }
\ No newline at end of file
@@ -0,0 +1,138 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Vector3 {
+  pub x: f32,
+  pub y: f32,
+  pub z: f32
+}
+
+impl Vector3 {
+  pub const ZERO: Self = Self { x: 0.0, y: 0.0, z: 0.0 };
+
+  pub fn new(x: f32, y: f32, z: f32) -> Self {
+    Self { x, y, z }
+  }
+
+  pub fn length(&self) -> f32 {
+    f32::sqrt(self.length_squared())
+  }
+
+  pub fn length_squared(&self) -> f32 {
+    self.dot(*self)
+  }
+
+  pub fn normalized(&self) -> Self {
+    let length = self.length();
+
+    if length == 0.0 {
+      return Self::ZERO;
+    }
+
+    *self * (1.0 / length)
+  }
+
+  pub fn dot(&self, other: Vector3) -> f32 {
+    (self.x * other.x) + (self.y * other.y) + (self.z * other.z)
+  }
+
+  pub fn cross(&self, other: Vector3) -> Self {
+    Self {
+      x: (self.y * other.z) - (self.z * other.y),
+      y: (self.z * other.x) - (self.x * other.z),
+      z: (self.x * other.y) - (self.y * other.x)
+    }
+  }
+}
+
+impl Add for Vector3 {
+  type Output = Self;
+
+  fn add(self, rhs: Self) -> Self::Output {
+    Self {
+      x: self.x + rhs.x,
+      y: self.y + rhs.y,
+      z: self.z + rhs.z
+    }
+  }
+}
+
+impl Sub for Vector3 {
+  type Output = Self;
+
+  fn sub(self, rhs: Self) -> Self::Output {
+    Self {
+      x: self.x - rhs.x,
+      y: self.y - rhs.y,
+      z: self.z - rhs.z
+    }
+  }
+}
+
+impl Neg for Vector3 {
+  type Output = Self;
+
+  fn neg(self) -> Self::Output {
+    Self {
+      x: -self.x,
+      y: -self.y,
+      z: -self.z
+    }
+  }
+}
+
+impl Mul<f32> for Vector3 {
+  type Output = Self;
+
+  fn mul(self, rhs: f32) -> Self::Output {
+    Self {
+      x: self.x * rhs,
+      y: self.y * rhs,
+      z: self.z * rhs
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn constructor() {
+    let vec3 = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_eq!(vec3.x, 1.0);
+    assert_eq!(vec3.y, 2.0);
+    assert_eq!(vec3.z, 3.0);
+  }
+
+  #[test]
+  fn arithmetic() {
+    let first = Vector3::new(1.0, 2.0, 3.0);
+    let second = Vector3::new(4.0, 5.0, 6.0);
+
+    assert_eq!(first + second, Vector3::new(5.0, 7.0, 9.0));
+    assert_eq!(second - first, Vector3::new(3.0, 3.0, 3.0));
+    assert_eq!(-first, Vector3::new(-1.0, -2.0, -3.0));
+    assert_eq!(first * 2.0, Vector3::new(2.0, 4.0, 6.0));
+  }
+
+  #[test]
+  fn products() {
+    let x_axis = Vector3::new(1.0, 0.0, 0.0);
+    let y_axis = Vector3::new(0.0, 1.0, 0.0);
+
+    assert_eq!(Vector3::new(1.0, 2.0, 3.0).dot(Vector3::new(4.0, 5.0, 6.0)), 32.0);
+    assert_eq!(x_axis.cross(y_axis), Vector3::new(0.0, 0.0, 1.0));
+    assert_eq!(y_axis.cross(x_axis), Vector3::new(0.0, 0.0, -1.0));
+  }
+
+  #[test]
+  fn normalization() {
+    let vec3 = Vector3::new(0.0, 3.0, 4.0);
+
+    assert_eq!(vec3.length(), 5.0);
+    assert_eq!(vec3.normalized(), Vector3::new(0.0, 0.6, 0.8));
+    assert_eq!(Vector3::ZERO.normalized(), Vector3::ZERO);
+  }
+}
\ No newline at end of file
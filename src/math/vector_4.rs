@@ -1,4 +1,4 @@
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub struct Vector4 {
   pub x: f32,
   pub y: f32,
@@ -10,6 +10,10 @@ impl Vector4 {
   pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
     Self { x, y, z, w }
   }
+
+  pub fn dot(&self, other: Vector4) -> f32 {
+    (self.x * other.x) + (self.y * other.y) + (self.z * other.z) + (self.w * other.w)
+  }
 }
 
 #[cfg(test)]
@@ -30,4 +34,12 @@ mod tests {
     assert_eq!(vec4.z, z);
     assert_eq!(vec4.w, w);
   }
+
+  #[test]
+  fn dot_product() {
+    let first = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    let second = Vector4::new(5.0, 6.0, 7.0, 8.0);
+
+    assert_eq!(first.dot(second), 70.0);
+  }
 }
\ No newline at end of file
@@ -0,0 +1,25 @@
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Player {
+  Left,
+  Right
+}
+
+impl Player {
+  pub fn opponent(&self) -> Self {
+    match self {
+      Player::Left => Player::Right,
+      Player::Right => Player::Left
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn opponent() {
+    assert_eq!(Player::Left.opponent(), Player::Right);
+    assert_eq!(Player::Right.opponent(), Player::Left);
+  }
+}
\ No newline at end of file
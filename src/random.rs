@@ -0,0 +1,148 @@
+const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+#[derive(Clone)]
+pub struct Random {
+  seed: u64,
+  state: u64
+}
+
+impl Random {
+  pub fn new(seed: u64) -> Self {
+    Self {
+      seed,
+      state: seed
+    }
+  }
+
+  pub fn seed(&self) -> u64 {
+    self.seed
+  }
+
+  pub fn next_u64(&mut self) -> u64 {
+    self.state = self.state.wrapping_add(GOLDEN_GAMMA);
+
+    let mut value = self.state;
+    value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    value ^ (value >> 31)
+  }
+
+  pub fn next_u32(&mut self) -> u32 {
+    (self.next_u64() >> 32) as u32
+  }
+
+  pub fn next_f32(&mut self) -> f32 {
+    (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+  }
+
+  pub fn range_f32(&mut self, min: f32, max: f32) -> f32 {
+    min + ((max - min) * self.next_f32())
+  }
+
+  pub fn range_u32(&mut self, min: u32, max: u32) -> u32 {
+    if max <= min {
+      return min;
+    }
+
+    let range = max - min;
+    let threshold = range.wrapping_neg() % range;
+
+    loop {
+      let product = u64::from(self.next_u32()) * u64::from(range);
+
+      if (product as u32) >= threshold {
+        return min + (product >> 32) as u32;
+      }
+    }
+  }
+
+  pub fn chance(&mut self, probability: f32) -> bool {
+    self.next_f32() < probability
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn known_sequence() {
+    let mut random = Random::new(0);
+
+    assert_eq!(random.next_u64(), 0xe220_a839_7b1d_cdaf);
+    assert_eq!(random.next_u64(), 0x6e78_9e6a_a1b9_65f4);
+    assert_eq!(random.next_u64(), 0x06c4_5d18_8009_454f);
+  }
+
+  #[test]
+  fn same_seed_same_sequence() {
+    let mut first = Random::new(42);
+    let mut second = Random::new(42);
+
+    for _ in 0..100 {
+      assert_eq!(first.next_u64(), second.next_u64());
+    }
+
+    assert_eq!(first.seed(), 42);
+  }
+
+  #[test]
+  fn different_seeds_diverge() {
+    let mut first = Random::new(1);
+    let mut second = Random::new(2);
+
+    assert_ne!(first.next_u64(), second.next_u64());
+  }
+
+  #[test]
+  fn float_ranges() {
+    let mut random = Random::new(7);
+
+    for _ in 0..1000 {
+      let unit = random.next_f32();
+      assert!((0.0..1.0).contains(&unit));
+
+      let ranged = random.range_f32(-4.0, 8.0);
+      assert!((-4.0..8.0).contains(&ranged));
+    }
+  }
+
+  #[test]
+  fn integer_ranges() {
+    let mut random = Random::new(7);
+
+    for _ in 0..1000 {
+      assert!((3..9).contains(&random.range_u32(3, 9)));
+    }
+
+    assert_eq!(random.range_u32(5, 5), 5);
+  }
+
+  #[test]
+  fn integer_range_distribution() {
+    let mut random = Random::new(11);
+    let mut counts = [0; 6];
+
+    for _ in 0..6000 {
+      counts[random.range_u32(0, 6) as usize] += 1;
+    }
+
+    for count in counts {
+      assert!((800..1200).contains(&count));
+    }
+
+    for _ in 0..1000 {
+      assert!(random.range_u32(0, u32::MAX) < u32::MAX);
+    }
+  }
+
+  #[test]
+  fn chance_extremes() {
+    let mut random = Random::new(7);
+
+    for _ in 0..100 {
+      assert!(!random.chance(0.0));
+      assert!(random.chance(1.0));
+    }
+  }
+}
\ No newline at end of file
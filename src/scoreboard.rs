@@ -0,0 +1,224 @@
+use crate::prelude::{
+  MatchEvent,
+  MatchRules,
+  Player
+};
+
+pub struct Scoreboard {
+  rules: MatchRules,
+  left_score: u32,
+  right_score: u32,
+  last_scorer: Option<Player>,
+  winner: Option<Player>,
+  serve_timer: Option<f32>
+}
+
+impl Scoreboard {
+  pub fn new(rules: MatchRules) -> Self {
+    Self {
+      rules,
+      left_score: 0,
+      right_score: 0,
+      last_scorer: None,
+      winner: None,
+      serve_timer: None
+    }
+  }
+
+  pub fn rules(&self) -> &MatchRules {
+    &self.rules
+  }
+
+  pub fn score(&self, player: Player) -> u32 {
+    match player {
+      Player::Left => self.left_score,
+      Player::Right => self.right_score
+    }
+  }
+
+  pub fn winner(&self) -> Option<Player> {
+    self.winner
+  }
+
+  pub fn is_match_over(&self) -> bool {
+    self.winner.is_some()
+  }
+
+  pub fn is_ball_in_play(&self) -> bool {
+    self.serve_timer.is_none() && self.winner.is_none()
+  }
+
+  pub fn next_receiver(&self) -> Player {
+    self.rules.serve_direction.receiver(self.last_scorer)
+  }
+
+  pub fn award_point(&mut self, scorer: Player) -> Vec<MatchEvent> {
+    let mut events = vec![];
+
+    if self.is_match_over() {
+      return events;
+    }
+
+    match scorer {
+      Player::Left => self.left_score += 1,
+      Player::Right => self.right_score += 1
+    }
+
+    self.last_scorer = Some(scorer);
+
+    events.push(MatchEvent::PointScored {
+      scorer,
+      left_score: self.left_score,
+      right_score: self.right_score
+    });
+
+    let scorer_score = self.score(scorer);
+    let opponent_score = self.score(scorer.opponent());
+
+    if scorer_score >= self.rules.target_score
+    && scorer_score >= opponent_score + self.rules.win_by {
+      self.winner = Some(scorer);
+      self.serve_timer = None;
+      events.push(MatchEvent::MatchWon { winner: scorer });
+    } else {
+      self.serve_timer = Some(self.rules.serve_delay);
+    }
+
+    events
+  }
+
+  pub fn update(&mut self, deltamillis: f32) -> Option<MatchEvent> {
+    let time_left = self.serve_timer? - deltamillis;
+
+    if time_left > 0.0 {
+      self.serve_timer = Some(time_left);
+      return None;
+    }
+
+    self.serve_timer = None;
+
+    Some(MatchEvent::BallServed { receiver: self.next_receiver() })
+  }
+
+  pub fn reset(&mut self) {
+    self.left_score = 0;
+    self.right_score = 0;
+    self.last_scorer = None;
+    self.winner = None;
+    self.serve_timer = None;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::prelude::ServeDirection;
+
+  fn rules(target_score: u32, win_by: u32) -> MatchRules {
+    MatchRules {
+      target_score,
+      win_by,
+      serve_delay: 500.0,
+      serve_direction: ServeDirection::TowardsConceder
+    }
+  }
+
+  #[test]
+  fn constructor() {
+    let scoreboard = Scoreboard::new(MatchRules::default());
+
+    assert_eq!(scoreboard.score(Player::Left), 0);
+    assert_eq!(scoreboard.score(Player::Right), 0);
+    assert_eq!(scoreboard.winner(), None);
+    assert!(scoreboard.is_ball_in_play());
+  }
+
+  #[test]
+  fn point_scored() {
+    let mut scoreboard = Scoreboard::new(rules(11, 2));
+
+    let events = scoreboard.award_point(Player::Left);
+
+    assert_eq!(events, vec![
+      MatchEvent::PointScored { scorer: Player::Left, left_score: 1, right_score: 0 }
+    ]);
+
+    assert_eq!(scoreboard.score(Player::Left), 1);
+    assert!(!scoreboard.is_ball_in_play());
+  }
+
+  #[test]
+  fn serve_after_delay() {
+    let mut scoreboard = Scoreboard::new(rules(11, 2));
+    scoreboard.award_point(Player::Left);
+
+    assert_eq!(scoreboard.update(250.0), None);
+    assert!(!scoreboard.is_ball_in_play());
+
+    assert_eq!(scoreboard.update(250.0), Some(MatchEvent::BallServed { receiver: Player::Right }));
+    assert!(scoreboard.is_ball_in_play());
+
+    assert_eq!(scoreboard.update(250.0), None);
+  }
+
+  #[test]
+  fn match_won_at_target() {
+    let mut scoreboard = Scoreboard::new(rules(3, 1));
+
+    scoreboard.award_point(Player::Right);
+    scoreboard.award_point(Player::Right);
+    let events = scoreboard.award_point(Player::Right);
+
+    assert_eq!(events, vec![
+      MatchEvent::PointScored { scorer: Player::Right, left_score: 0, right_score: 3 },
+      MatchEvent::MatchWon { winner: Player::Right }
+    ]);
+
+    assert_eq!(scoreboard.winner(), Some(Player::Right));
+    assert!(scoreboard.is_match_over());
+    assert!(!scoreboard.is_ball_in_play());
+    assert_eq!(scoreboard.update(1000.0), None);
+  }
+
+  #[test]
+  fn win_by_two() {
+    let mut scoreboard = Scoreboard::new(rules(3, 2));
+
+    scoreboard.award_point(Player::Left);
+    scoreboard.award_point(Player::Left);
+    scoreboard.award_point(Player::Right);
+    scoreboard.award_point(Player::Right);
+    scoreboard.award_point(Player::Left);
+
+    assert_eq!(scoreboard.winner(), None);
+
+    scoreboard.award_point(Player::Left);
+
+    assert_eq!(scoreboard.winner(), Some(Player::Left));
+    assert_eq!(scoreboard.score(Player::Left), 4);
+    assert_eq!(scoreboard.score(Player::Right), 2);
+  }
+
+  #[test]
+  fn no_points_after_match() {
+    let mut scoreboard = Scoreboard::new(rules(1, 1));
+
+    scoreboard.award_point(Player::Left);
+    let events = scoreboard.award_point(Player::Right);
+
+    assert!(events.is_empty());
+    assert_eq!(scoreboard.score(Player::Right), 0);
+  }
+
+  #[test]
+  fn reset() {
+    let mut scoreboard = Scoreboard::new(rules(1, 1));
+
+    scoreboard.award_point(Player::Left);
+    scoreboard.reset();
+
+    assert_eq!(scoreboard.score(Player::Left), 0);
+    assert_eq!(scoreboard.winner(), None);
+    assert!(scoreboard.is_ball_in_play());
+  }
+}
\ No newline at end of file
@@ -0,0 +1,43 @@
+use crate::prelude::Player;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum ServeDirection {
+  Left,
+  Right,
+  TowardsScorer,
+  TowardsConceder
+}
+
+impl ServeDirection {
+  pub fn receiver(&self, last_scorer: Option<Player>) -> Player {
+    match self {
+      ServeDirection::Left => Player::Left,
+      ServeDirection::Right => Player::Right,
+      ServeDirection::TowardsScorer => last_scorer.unwrap_or(Player::Right),
+      ServeDirection::TowardsConceder => last_scorer.map(|scorer| scorer.opponent()).unwrap_or(Player::Right)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn fixed_directions() {
+    assert_eq!(ServeDirection::Left.receiver(Some(Player::Right)), Player::Left);
+    assert_eq!(ServeDirection::Right.receiver(Some(Player::Left)), Player::Right);
+  }
+
+  #[test]
+  fn relative_directions() {
+    assert_eq!(ServeDirection::TowardsScorer.receiver(Some(Player::Left)), Player::Left);
+    assert_eq!(ServeDirection::TowardsConceder.receiver(Some(Player::Left)), Player::Right);
+  }
+
+  #[test]
+  fn opening_serve() {
+    assert_eq!(ServeDirection::TowardsScorer.receiver(None), Player::Right);
+    assert_eq!(ServeDirection::TowardsConceder.receiver(None), Player::Right);
+  }
+}
\ No newline at end of file
@@ -1,45 +1,83 @@
-use std::{ffi::CString, fs, path::Path};
+use std::{ffi::CString, fs, path::{Path, PathBuf}};
+
+use crate::prelude::{ShaderError, ShaderStage};
 
 pub struct Shader {
-  id: gl::types::GLuint
+  id: gl::types::GLuint,
+  path: PathBuf,
+  stage: ShaderStage
 }
 
 impl Shader {
-  pub fn vertex(file_path: &Path) -> Result<Self, String> {
-    let shader_source = CString::new(
-      fs::read_to_string(file_path).map_err(|error| error.to_string())?
-    ).map_err(|error| error.to_string())?;
-  
-    let id = unsafe { gl::CreateShader(gl::VERTEX_SHADER) };
-    unsafe {
-      gl::ShaderSource(id, 1, &shader_source.as_ptr(), std::ptr::null());
-      gl::CompileShader(id);
-    }
+  pub fn vertex(file_path: &Path) -> Result<Self, ShaderError> {
+    Self::compile(ShaderStage::Vertex, file_path)
+  }
 
-    Ok(
-      Self { id }
-    )
+  pub fn fragment(file_path: &Path) -> Result<Self, ShaderError> {
+    Self::compile(ShaderStage::Fragment, file_path)
   }
 
-  pub fn fragment(file_path: &Path) -> Result<Self, String> {
-    let shader_source = CString::new(
-      fs::read_to_string(file_path).map_err(|error| error.to_string())?
-    ).map_err(|error| error.to_string())?;
-  
-    let id = unsafe { gl::CreateShader(gl::FRAGMENT_SHADER) };
+  pub fn compile(stage: ShaderStage, file_path: &Path) -> Result<Self, ShaderError> {
+    let read_error = |message: String| ShaderError::Read {
+      path: file_path.to_path_buf(),
+      stage,
+      message
+    };
+
+    let source = fs::read_to_string(file_path).map_err(|error| read_error(error.to_string()))?;
+    let shader_source = CString::new(source.as_str()).map_err(|error| read_error(error.to_string()))?;
+
+    let shader = Self {
+      id: unsafe { gl::CreateShader(stage.gl_type()) },
+      path: file_path.to_path_buf(),
+      stage
+    };
+
+    let mut compile_status: gl::types::GLint = 0;
     unsafe {
-      gl::ShaderSource(id, 1, &shader_source.as_ptr(), std::ptr::null());
-      gl::CompileShader(id);
+      gl::ShaderSource(shader.id, 1, &shader_source.as_ptr(), std::ptr::null());
+      gl::CompileShader(shader.id);
+      gl::GetShaderiv(shader.id, gl::COMPILE_STATUS, &mut compile_status);
     }
 
-    Ok(
-      Self { id }
-    )
+    if compile_status == gl::FALSE as gl::types::GLint {
+      return Err(ShaderError::compile(file_path.to_path_buf(), stage, &shader.info_log(), &source));
+    }
+
+    Ok(shader)
   }
 
   pub fn id(&self) -> gl::types::GLuint {
     self.id
   }
+
+  pub fn path(&self) -> &Path {
+    &self.path
+  }
+
+  pub fn stage(&self) -> ShaderStage {
+    self.stage
+  }
+
+  fn info_log(&self) -> String {
+    let mut log_length: gl::types::GLint = 0;
+    unsafe { gl::GetShaderiv(self.id, gl::INFO_LOG_LENGTH, &mut log_length); }
+
+    let mut info_log = vec![0u8; log_length.max(1) as usize];
+    let mut written: gl::types::GLsizei = 0;
+
+    unsafe {
+      gl::GetShaderInfoLog(
+        self.id,
+        info_log.len() as gl::types::GLsizei,
+        &mut written,
+        info_log.as_mut_ptr() as *mut gl::types::GLchar
+      );
+    }
+
+    info_log.truncate(written.max(0) as usize);
+    String::from_utf8_lossy(&info_log).into_owned()
+  }
 }
 
 impl Drop for Shader {
@@ -0,0 +1,133 @@
+use std::{fmt, path::PathBuf};
+
+use crate::prelude::ShaderStage;
+
+#[derive(PartialEq, Debug)]
+pub enum ShaderError {
+  Read { path: PathBuf, stage: ShaderStage, message: String },
+  Compile { path: PathBuf, stage: ShaderStage, info_log: String },
+  Link { shaders: Vec<(ShaderStage, PathBuf)>, info_log: String }
+}
+
+impl ShaderError {
+  pub fn compile(path: PathBuf, stage: ShaderStage, info_log: &str, source: &str) -> Self {
+    ShaderError::Compile {
+      path,
+      stage,
+      info_log: annotate_info_log(info_log, source)
+    }
+  }
+}
+
+impl fmt::Display for ShaderError {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      ShaderError::Read { path, stage, message } => {
+        write!(formatter, "Failed to read {stage} shader {}: {message}", path.display())
+      },
+
+      ShaderError::Compile { path, stage, info_log } => {
+        write!(formatter, "Failed to compile {stage} shader {}:\n{info_log}", path.display())
+      },
+
+      ShaderError::Link { shaders, info_log } => {
+        let linked_shaders: Vec<String> = shaders
+          .iter()
+          .map(|(stage, path)| format!("{stage} shader {}", path.display()))
+          .collect();
+
+        write!(formatter, "Failed to link shader program ({}):\n{info_log}", linked_shaders.join(", "))
+      }
+    }
+  }
+}
+
+impl std::error::Error for ShaderError {}
+
+impl From<ShaderError> for String {
+  fn from(error: ShaderError) -> Self {
+    error.to_string()
+  }
+}
+
+fn annotate_info_log(info_log: &str, source: &str) -> String {
+  let source_lines: Vec<&str> = source.lines().collect();
+  let mut annotated = Vec::new();
+
+  for log_line in info_log.lines().map(str::trim_end).filter(|log_line| !log_line.is_empty()) {
+    annotated.push(log_line.to_string());
+
+    if let Some(line_number) = parse_line_number(log_line)
+    && let Some(source_line) = line_number.checked_sub(1).and_then(|index| source_lines.get(index)) {
+      annotated.push(format!("  {line_number} | {}", source_line.trim_end()));
+    }
+  }
+
+  annotated.join("\n")
+}
+
+fn parse_line_number(log_line: &str) -> Option<usize> {
+  let location = log_line.trim_start().trim_start_matches("ERROR:").trim_start_matches("WARNING:").trim_start();
+
+  let after_source = location.strip_prefix(|character: char| character.is_ascii_digit())?;
+  let after_source = after_source.trim_start_matches(|character: char| character.is_ascii_digit());
+  let after_separator = after_source.strip_prefix(':').or_else(|| after_source.strip_prefix('('))?;
+
+  let digits: String = after_separator.chars().take_while(char::is_ascii_digit).collect();
+  digits.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const SOURCE: &str = "#version 330 core\n\nvoid main() {\n  color = texure(tex, coords);\n}";
+
+  #[test]
+  fn line_numbers() {
+    assert_eq!(parse_line_number("0:4(11): error: no function with name 'texure'"), Some(4));
+    assert_eq!(parse_line_number("0(4) : error C1008: undefined variable \"texure\""), Some(4));
+    assert_eq!(parse_line_number("ERROR: 0:12: 'texure' : no matching overloaded function found"), Some(12));
+    assert_eq!(parse_line_number("error: linking failed"), None);
+  }
+
+  #[test]
+  fn annotated_log() {
+    let info_log = "0:4(11): error: no function with name 'texure'\n0:40(1): error: out of range\n\n";
+
+    let annotated = annotate_info_log(info_log, SOURCE);
+
+    assert_eq!(
+      annotated,
+      "0:4(11): error: no function with name 'texure'\n  4 |   color = texure(tex, coords);\n0:40(1): error: out of range"
+    );
+  }
+
+  #[test]
+  fn error_messages() {
+    let compile_error = ShaderError::compile(
+      PathBuf::from("res/shaders/fragment_shader.glsl"),
+      ShaderStage::Fragment,
+      "0:4(11): error: no function with name 'texure'",
+      SOURCE
+    );
+
+    let message: String = compile_error.into();
+
+    assert!(message.starts_with("Failed to compile fragment shader res/shaders/fragment_shader.glsl:\n"));
+    assert!(message.ends_with("  4 |   color = texure(tex, coords);"));
+
+    let link_error = ShaderError::Link {
+      shaders: vec![
+        (ShaderStage::Vertex, PathBuf::from("res/shaders/vertex_shader.glsl")),
+        (ShaderStage::Fragment, PathBuf::from("res/shaders/fragment_shader.glsl"))
+      ],
+      info_log: "error: vertexColor not written".to_string()
+    };
+
+    assert_eq!(
+      link_error.to_string(),
+      "Failed to link shader program (vertex shader res/shaders/vertex_shader.glsl, fragment shader res/shaders/fragment_shader.glsl):\nerror: vertexColor not written"
+    );
+  }
+}
\ No newline at end of file
@@ -1,37 +1,109 @@
-use std::ffi::CString;
+use std::{cell::RefCell, collections::{HashMap, HashSet}, ffi::{CStr, CString}};
 
-use crate::prelude::{Shader, Matrix4};
+use crate::prelude::{Shader, ShaderError, Matrix4, UniformValue};
 
 pub struct ShaderProgram {
-  id: gl::types::GLuint
+  id: gl::types::GLuint,
+  uniform_locations: UniformLocationCache
 }
 
 impl ShaderProgram {
-  pub fn link(shaders: Vec<Shader>) -> Self {
-    let id = unsafe { gl::CreateProgram() };
+  pub fn link(shaders: Vec<Shader>) -> Result<Self, ShaderError> {
+    let shader_program = Self {
+      id: unsafe { gl::CreateProgram() },
+      uniform_locations: UniformLocationCache::default()
+    };
 
     for shader in &shaders {
       unsafe {
-        gl::AttachShader(id, shader.id());
+        gl::AttachShader(shader_program.id, shader.id());
       }
     }
 
+    let mut link_status: gl::types::GLint = 0;
     unsafe {
-      gl::LinkProgram(id);
+      gl::LinkProgram(shader_program.id);
+      gl::GetProgramiv(shader_program.id, gl::LINK_STATUS, &mut link_status);
     }
 
     for shader in &shaders {
       unsafe {
-        gl::DetachShader(id, shader.id());
+        gl::DetachShader(shader_program.id, shader.id());
       }
     }
 
-    Self { id }
+    if link_status == gl::FALSE as gl::types::GLint {
+      return Err(ShaderError::Link {
+        shaders: shaders.iter().map(|shader| (shader.stage(), shader.path().to_path_buf())).collect(),
+        info_log: shader_program.info_log()
+      });
+    }
+
+    Ok(shader_program)
   }
 
   pub fn id(&self) -> gl::types::GLuint {
     self.id
   }
+
+  pub fn bind(&self) {
+    unsafe {
+      gl::UseProgram(self.id);
+    }
+  }
+
+  pub fn uniform_location(&self, uniform_name: &CStr) -> gl::types::GLint {
+    self.uniform_locations.get_or_insert_with(uniform_name, || unsafe {
+      gl::GetUniformLocation(self.id, uniform_name.as_ptr())
+    })
+  }
+
+  pub fn require_uniform(&self, uniform_name: &CStr) -> Result<gl::types::GLint, String> {
+    match self.uniform_location(uniform_name) {
+      -1 => Err(format!("Unknown uniform '{}'", uniform_name.to_string_lossy())),
+      location => Ok(location)
+    }
+  }
+
+  pub fn set_uniform<T: UniformValue>(&self, uniform_name: &CStr, value: T) -> Result<(), String> {
+    let location = self.uniform_location(uniform_name);
+
+    if location == -1 {
+      if self.uniform_locations.report_missing(uniform_name) {
+        eprintln!("Uniform '{}' is not active in shader program {}", uniform_name.to_string_lossy(), self.id);
+      }
+
+      return Ok(());
+    }
+
+    value.upload(location);
+
+    Ok(())
+  }
+
+  fn info_log(&self) -> String {
+    let mut log_length: gl::types::GLint = 0;
+    unsafe { gl::GetProgramiv(self.id, gl::INFO_LOG_LENGTH, &mut log_length); }
+
+    let mut info_log = vec![0u8; log_length.max(1) as usize];
+    let mut written: gl::types::GLsizei = 0;
+
+    unsafe {
+      gl::GetProgramInfoLog(
+        self.id,
+        info_log.len() as gl::types::GLsizei,
+        &mut written,
+        info_log.as_mut_ptr() as *mut gl::types::GLchar
+      );
+    }
+
+    info_log.truncate(written.max(0) as usize);
+    String::from_utf8_lossy(&info_log).trim_end().to_string()
+  }
+
+  pub fn set_sampler(&self, uniform_name: &CStr, texture_unit: u32) -> Result<(), String> {
+    self.set_uniform(uniform_name, texture_unit as i32)
+  }
 }
 
 impl Drop for ShaderProgram {
@@ -42,28 +114,64 @@ impl Drop for ShaderProgram {
   }
 }
 
-pub fn set_model_matrix(shader_program: &ShaderProgram, matrix: &Matrix4) -> Result<(), String> {
-  set_uniform_mat4(shader_program, "model", matrix)?;
+#[derive(Default)]
+struct UniformLocationCache {
+  locations: RefCell<HashMap<CString, gl::types::GLint>>,
+  reported_missing: RefCell<HashSet<CString>>
+}
+
+impl UniformLocationCache {
+  fn get_or_insert_with(&self, uniform_name: &CStr, lookup: impl FnOnce() -> gl::types::GLint) -> gl::types::GLint {
+    if let Some(location) = self.locations.borrow().get(uniform_name) {
+      return *location;
+    }
+
+    let location = lookup();
+    self.locations.borrow_mut().insert(uniform_name.to_owned(), location);
+    location
+  }
 
-  Ok(())
+  fn report_missing(&self, uniform_name: &CStr) -> bool {
+    self.reported_missing.borrow_mut().insert(uniform_name.to_owned())
+  }
 }
 
-pub fn set_view_matrix(shader_program: &ShaderProgram, matrix: &Matrix4) -> Result<(), String> {
-  set_uniform_mat4(shader_program, "view", matrix)?;
+pub fn set_model_matrix(shader_program: &ShaderProgram, matrix: &Matrix4) -> Result<(), String> {
+  shader_program.set_uniform(c"model", *matrix)
+}
 
-  Ok(())
+pub fn set_view_matrix(shader_program: &ShaderProgram, matrix: &Matrix4) -> Result<(), String> {
+  shader_program.set_uniform(c"view", *matrix)
 }
 
 pub fn set_projection_matrix(shader_program: &ShaderProgram, matrix: &Matrix4) -> Result<(), String> {
-  set_uniform_mat4(shader_program, "projection", matrix)?;
-
-  Ok(())
+  shader_program.set_uniform(c"projection", *matrix)
 }
 
-fn set_uniform_mat4(shader_program: &ShaderProgram, uniform_name: &str, matrix: &Matrix4) -> Result<(), String>{
-  let uniform_name = CString::new(uniform_name).map_err(|error| error.to_string())?;
-  let uniform_location = unsafe { gl::GetUniformLocation(shader_program.id(), uniform_name.as_ptr()) };
-  unsafe { gl::UniformMatrix4fv(uniform_location, 1, gl::FALSE, matrix.flatten().as_ptr()); }
+#[cfg(test)]
+mod tests {
+  use super::*;
 
-  Ok(())
+  #[test]
+  fn cached_locations() {
+    let cache = UniformLocationCache::default();
+    let mut lookups = 0;
+
+    let first = cache.get_or_insert_with(c"tint", || { lookups += 1; 3 });
+    let second = cache.get_or_insert_with(c"tint", || { lookups += 1; 7 });
+    let other = cache.get_or_insert_with(c"model", || { lookups += 1; 1 });
+
+    assert_eq!(first, 3);
+    assert_eq!(second, 3);
+    assert_eq!(other, 1);
+    assert_eq!(lookups, 2);
+  }
+  #[test]
+  fn missing_reported_once() {
+    let cache = UniformLocationCache::default();
+
+    assert!(cache.report_missing(c"tint"));
+    assert!(!cache.report_missing(c"tint"));
+    assert!(cache.report_missing(c"model"));
+  }
 }
\ No newline at end of file
@@ -0,0 +1,25 @@
+use std::fmt;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ShaderStage {
+  Vertex,
+  Fragment
+}
+
+impl ShaderStage {
+  pub fn gl_type(&self) -> gl::types::GLenum {
+    match self {
+      ShaderStage::Vertex => gl::VERTEX_SHADER,
+      ShaderStage::Fragment => gl::FRAGMENT_SHADER
+    }
+  }
+}
+
+impl fmt::Display for ShaderStage {
+  fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      ShaderStage::Vertex => write!(formatter, "vertex"),
+      ShaderStage::Fragment => write!(formatter, "fragment")
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,149 @@
+use crate::prelude::{Color, Rect, Vector2};
+
+const FLOATS_PER_VERTEX: usize = 6;
+
+pub fn generate_rect_vertex_data(rect: Rect, color: Color) -> Vec<f32> {
+  let top_left = rect.min();
+  let bottom_right = rect.max();
+  let top_right = Vector2::new(rect.right(), rect.top());
+  let bottom_left = Vector2::new(rect.left(), rect.bottom());
+
+  triangles(&[top_left, top_right, bottom_right, top_left, bottom_right, bottom_left], color)
+}
+
+pub fn generate_rect_outline_vertex_data(rect: Rect, thickness: f32, color: Color) -> Vec<f32> {
+  let thickness = thickness.min(rect.width() / 2.0).min(rect.height() / 2.0);
+  let inner_top = rect.top() + thickness;
+  let inner_bottom = rect.bottom() - thickness;
+
+  let edges = [
+    Rect::from_corners(rect.min(), Vector2::new(rect.right(), inner_top)),
+    Rect::from_corners(Vector2::new(rect.left(), inner_bottom), rect.max()),
+    Rect::from_corners(Vector2::new(rect.left(), inner_top), Vector2::new(rect.left() + thickness, inner_bottom)),
+    Rect::from_corners(Vector2::new(rect.right() - thickness, inner_top), Vector2::new(rect.right(), inner_bottom))
+  ];
+
+  edges.into_iter().flat_map(|edge| generate_rect_vertex_data(edge, color)).collect()
+}
+
+pub fn generate_line_vertex_data(start: Vector2, end: Vector2, thickness: f32, color: Color) -> Vec<f32> {
+  let offset = (end - start).normalized().perp() * (thickness / 2.0);
+
+  triangles(&[start - offset, end - offset, end + offset, start - offset, end + offset, start + offset], color)
+}
+
+pub fn generate_circle_vertex_data(center: Vector2, radius: f32, segments: u32, color: Color) -> Vec<f32> {
+  let points: Vec<Vector2> = circle_points(center, radius, segments)
+    .zip(circle_points(center, radius, segments).skip(1))
+    .flat_map(|(first, second)| [center, first, second])
+    .collect();
+
+  triangles(&points, color)
+}
+
+pub fn generate_circle_outline_vertex_data(center: Vector2, radius: f32, thickness: f32, segments: u32, color: Color) -> Vec<f32> {
+  let inner_radius = (radius - thickness).max(0.0);
+
+  let outer_points: Vec<Vector2> = circle_points(center, radius, segments).collect();
+  let inner_points: Vec<Vector2> = circle_points(center, inner_radius, segments).collect();
+
+  let points: Vec<Vector2> = outer_points
+    .windows(2)
+    .zip(inner_points.windows(2))
+    .flat_map(|(outer, inner)| [outer[0], outer[1], inner[1], outer[0], inner[1], inner[0]])
+    .collect();
+
+  triangles(&points, color)
+}
+
+fn circle_points(center: Vector2, radius: f32, segments: u32) -> impl Iterator<Item = Vector2> {
+  let segments = segments.max(3);
+
+  (0..=segments).map(move |segment| {
+    let angle = (segment % segments) as f32 / segments as f32 * std::f32::consts::TAU;
+    center + (Vector2::new(angle.cos(), angle.sin()) * radius)
+  })
+}
+
+fn triangles(points: &[Vector2], color: Color) -> Vec<f32> {
+  let mut vertex_data = Vec::with_capacity(points.len() * FLOATS_PER_VERTEX);
+
+  for point in points {
+    vertex_data.extend_from_slice(&[point.x, point.y, color.r, color.g, color.b, color.a]);
+  }
+
+  vertex_data
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn positions(vertex_data: &[f32]) -> Vec<Vector2> {
+    vertex_data
+      .chunks(FLOATS_PER_VERTEX)
+      .map(|vertex| Vector2::new(vertex[0], vertex[1]))
+      .collect()
+  }
+
+  #[test]
+  fn rect_data() {
+    let rect = Rect::from_corners(Vector2::new(10.0, 20.0), Vector2::new(30.0, 60.0));
+
+    let vertex_data = generate_rect_vertex_data(rect, Color::RED);
+
+    assert_eq!(vertex_data.len(), 6 * FLOATS_PER_VERTEX);
+    assert_eq!(&vertex_data[0..6], &[10.0, 20.0, 1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(positions(&vertex_data)[2], Vector2::new(30.0, 60.0));
+    assert_eq!(positions(&vertex_data)[5], Vector2::new(10.0, 60.0));
+  }
+
+  #[test]
+  fn rect_outline_data() {
+    let rect = Rect::from_corners(Vector2::ZERO, Vector2::new(10.0, 10.0));
+
+    let vertex_data = generate_rect_outline_vertex_data(rect, 2.0, Color::WHITE);
+    let points = positions(&vertex_data);
+
+    assert_eq!(points.len(), 24);
+    assert!(points.iter().all(|point| rect.contains_point(*point)));
+    assert!(points.iter().all(|point| !(point.x > 2.0 && point.x < 8.0 && point.y > 2.0 && point.y < 8.0)));
+  }
+
+  #[test]
+  fn line_data() {
+    let vertex_data = generate_line_vertex_data(Vector2::ZERO, Vector2::new(10.0, 0.0), 4.0, Color::WHITE);
+    let points = positions(&vertex_data);
+
+    assert_eq!(points.len(), 6);
+    assert_eq!(points[0], Vector2::new(0.0, -2.0));
+    assert_eq!(points[1], Vector2::new(10.0, -2.0));
+    assert_eq!(points[2], Vector2::new(10.0, 2.0));
+    assert_eq!(points[5], Vector2::new(0.0, 2.0));
+  }
+
+  #[test]
+  fn circle_data() {
+    let center = Vector2::new(5.0, 5.0);
+
+    let points = positions(&generate_circle_vertex_data(center, 3.0, 16, Color::WHITE));
+
+    assert_eq!(points.len(), 16 * 3);
+    assert!(points.iter().step_by(3).all(|point| *point == center));
+    assert!(points.iter().enumerate().filter(|(index, _)| index % 3 != 0).all(|(_, point)| (point.distance(center) - 3.0).abs() < 0.0001));
+    assert_eq!(generate_circle_vertex_data(center, 3.0, 1, Color::WHITE).len(), 3 * 3 * FLOATS_PER_VERTEX);
+  }
+
+  #[test]
+  fn circle_outline_data() {
+    let center = Vector2::ZERO;
+
+    let points = positions(&generate_circle_outline_vertex_data(center, 8.0, 2.0, 8, Color::WHITE));
+
+    assert_eq!(points.len(), 8 * 6);
+    assert!(points.iter().all(|point| {
+      let distance = point.distance(center);
+      (distance - 8.0).abs() < 0.0001 || (distance - 6.0).abs() < 0.0001
+    }));
+  }
+}
\ No newline at end of file
@@ -0,0 +1,101 @@
+use crate::prelude::{
+  BufferObject,
+  Color,
+  Matrix4,
+  Rect,
+  ShaderProgram,
+  Vector2,
+  VertexArray,
+  generate_circle_outline_vertex_data,
+  generate_circle_vertex_data,
+  generate_line_vertex_data,
+  generate_rect_outline_vertex_data,
+  generate_rect_vertex_data,
+  set_model_matrix
+};
+
+const FLOATS_PER_VERTEX: usize = 6;
+const FLOATS_PER_TRIANGLE: usize = FLOATS_PER_VERTEX * 3;
+const CIRCLE_SEGMENTS: u32 = 32;
+
+pub struct ShapeRenderer {
+  capacity: usize,
+  vertex_data: Vec<f32>,
+  vertex_buffer: BufferObject,
+  vertex_array: VertexArray
+}
+
+impl ShapeRenderer {
+  pub fn new(triangle_capacity: usize) -> Result<Self, String> {
+    if triangle_capacity == 0 {
+      return Err("Shape renderer capacity must be greater than zero".to_string());
+    }
+
+    let capacity = triangle_capacity * FLOATS_PER_TRIANGLE;
+    let vertex_buffer = BufferObject::dynamic_vertex_array(capacity);
+    let vertex_array = VertexArray::colored(&vertex_buffer);
+
+    Ok(
+      Self {
+        capacity,
+        vertex_data: Vec::with_capacity(capacity),
+        vertex_buffer,
+        vertex_array
+      }
+    )
+  }
+
+  pub fn fill_rect(&mut self, rect: Rect, color: Color) {
+    self.vertex_data.extend(generate_rect_vertex_data(rect, color));
+  }
+
+  pub fn stroke_rect(&mut self, rect: Rect, thickness: f32, color: Color) {
+    self.vertex_data.extend(generate_rect_outline_vertex_data(rect, thickness, color));
+  }
+
+  pub fn line(&mut self, start: Vector2, end: Vector2, thickness: f32, color: Color) {
+    self.vertex_data.extend(generate_line_vertex_data(start, end, thickness, color));
+  }
+
+  pub fn fill_circle(&mut self, center: Vector2, radius: f32, color: Color) {
+    self.vertex_data.extend(generate_circle_vertex_data(center, radius, CIRCLE_SEGMENTS, color));
+  }
+
+  pub fn stroke_circle(&mut self, center: Vector2, radius: f32, thickness: f32, color: Color) {
+    self.vertex_data.extend(generate_circle_outline_vertex_data(center, radius, thickness, CIRCLE_SEGMENTS, color));
+  }
+
+  pub fn flush(&mut self, shader_program: &ShaderProgram) -> Result<(), String> {
+    if self.vertex_data.is_empty() {
+      return Ok(());
+    }
+
+    shader_program.bind();
+    set_model_matrix(shader_program, &Matrix4::identity())?;
+
+    self.vertex_array.bind();
+
+    for chunk in self.vertex_data.chunks(self.capacity) {
+      self.vertex_buffer.update_vertex_data(chunk);
+
+      unsafe {
+        gl::DrawArrays(gl::TRIANGLES, 0, (chunk.len() / FLOATS_PER_VERTEX) as gl::types::GLsizei);
+      }
+    }
+
+    self.vertex_array.unbind();
+    self.vertex_data.clear();
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn zero_capacity() {
+    assert!(ShapeRenderer::new(0).is_err());
+  }
+}
\ No newline at end of file
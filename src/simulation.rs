@@ -0,0 +1,1158 @@
+use crate::prelude::{
+  aabb_cast,
+  deflect_from_paddle,
+  find_collisions,
+  find_swept_collision,
+  resolve_collision,
+  resolve_swept_collision,
+  Collider,
+  ColliderHandle,
+  ColliderWorld,
+  Collision,
+  CollisionEvent,
+  CollisionLayer,
+  CollisionPhase,
+  CollisionResolution,
+  CollisionTracker,
+  Inputs,
+  Transform,
+  MatchEvent,
+  MatchRules,
+  Player,
+  Random,
+  Rect,
+  Scoreboard,
+  SweptCollision,
+  Vector2
+};
+
+const BALL_SIZE: f32 = 16.0;
+const BALL_SPEED: f32 = 0.5;
+const BALL_RESTITUTION: f32 = 1.0;
+const MAX_DEFLECTION_ANGLE: f32 = std::f32::consts::FRAC_PI_4;
+const PADDLE_WIDTH: f32 = 16.0;
+const PADDLE_HEIGHT: f32 = 128.0;
+const PADDLE_SPEED: f32 = 0.5;
+const PADDLE_INSET: f32 = 32.0;
+const POWER_UP_SIZE: f32 = 16.0;
+const POWER_UP_COUNT: usize = 4;
+const POWER_UP_MARGIN: f32 = 64.0;
+const MAX_POWER_UP_PLACEMENT_ATTEMPTS: usize = 64;
+const POWER_UP_SLOT_SPACING: f32 = POWER_UP_SIZE * 2.0;
+const MIN_ARENA_WIDTH: f32 = POWER_UP_SLOT_SPACING * 6.0;
+const MIN_ARENA_HEIGHT: f32 = POWER_UP_MARGIN * 2.0 + POWER_UP_SLOT_SPACING * 3.0;
+const BARRIER_THICKNESS: f32 = 8.0;
+const MAX_PREDICTED_BOUNCES: u32 = 8;
+const MAX_BALL_SWEEPS: u32 = 4;
+const MAX_SERVE_ANGLE: f32 = std::f32::consts::FRAC_PI_4;
+const MAX_AI_ERROR: f32 = 48.0;
+const DEFAULT_SEED: u64 = 0x5eed;
+
+const BALL_LAYER: CollisionLayer = CollisionLayer::from_bits(1 << 0);
+const PADDLE_LAYER: CollisionLayer = CollisionLayer::from_bits(1 << 1);
+const BARRIER_LAYER: CollisionLayer = CollisionLayer::from_bits(1 << 2);
+const POWER_UP_LAYER: CollisionLayer = CollisionLayer::from_bits(1 << 3);
+
+pub struct Simulation {
+  arena_width: f32,
+  arena_height: f32,
+  scoreboard: Scoreboard,
+  ball_transform: Transform,
+  previous_ball_transform: Transform,
+  ball_velocity: Vector2,
+  left_paddle_transform: Transform,
+  previous_left_paddle_transform: Transform,
+  left_paddle_velocity: Vector2,
+  right_paddle_transform: Transform,
+  previous_right_paddle_transform: Transform,
+  right_paddle_velocity: Vector2,
+  power_up_transforms: Vec<Transform>,
+  colliders: ColliderWorld,
+  collision_tracker: CollisionTracker,
+  collision_events: Vec<CollisionEvent>,
+  collisions: Vec<Collision>,
+  power_up_collider_handles: Vec<ColliderHandle>,
+  ball_collider_handle: ColliderHandle,
+  left_paddle_collider_handle: ColliderHandle,
+  right_paddle_collider_handle: ColliderHandle,
+  left_barrier_collider_handle: ColliderHandle,
+  right_barrier_collider_handle: ColliderHandle,
+  top_barrier_collider_handle: ColliderHandle,
+  bottom_barrier_collider_handle: ColliderHandle,
+  random: Random,
+  ai_error: f32,
+  returned_by: Option<Player>
+}
+
+impl Simulation {
+  pub fn new(arena_width: f32, arena_height: f32) -> Self {
+    Self::with_rules(arena_width, arena_height, MatchRules::default())
+  }
+
+  pub fn with_rules(arena_width: f32, arena_height: f32, rules: MatchRules) -> Self {
+    Self::with_seed(arena_width, arena_height, rules, DEFAULT_SEED)
+  }
+
+  pub fn with_seed(arena_width: f32, arena_height: f32, rules: MatchRules, seed: u64) -> Self {
+    let arena_width = arena_width.max(MIN_ARENA_WIDTH);
+    let arena_height = arena_height.max(MIN_ARENA_HEIGHT);
+    let mut random = Random::new(seed);
+
+    let power_up_transforms = place_power_ups(arena_width, arena_height, &mut random);
+
+    let ball_transform = Transform::new(arena_width / 2.0, arena_height / 2.0);
+    let left_paddle_transform = Transform::new(PADDLE_INSET, arena_height / 2.0);
+    let right_paddle_transform = Transform::new(arena_width - PADDLE_INSET, arena_height / 2.0);
+
+    let mut colliders = ColliderWorld::new();
+
+    let power_up_collider_handles = insert_power_up_colliders(&mut colliders, &power_up_transforms);
+
+    let ball_collider_handle = colliders.insert(
+      Collider::new_circle(ball_transform.x(), ball_transform.y(), BALL_SIZE / 2.0)
+        .with_layer(BALL_LAYER, PADDLE_LAYER | BARRIER_LAYER | POWER_UP_LAYER)
+    );
+
+    let left_paddle_collider_handle = colliders.insert(
+      Collider::new(left_paddle_transform.x(), left_paddle_transform.y(), PADDLE_WIDTH, PADDLE_HEIGHT)
+        .with_layer(PADDLE_LAYER, BALL_LAYER | BARRIER_LAYER)
+    );
+
+    let right_paddle_collider_handle = colliders.insert(
+      Collider::new(right_paddle_transform.x(), right_paddle_transform.y(), PADDLE_WIDTH, PADDLE_HEIGHT)
+        .with_layer(PADDLE_LAYER, BALL_LAYER | BARRIER_LAYER)
+    );
+
+    let left_barrier_collider_handle = colliders.insert(
+      Collider::new_static(0.0, arena_height / 2.0, BARRIER_THICKNESS, arena_height)
+        .with_layer(BARRIER_LAYER, BALL_LAYER | PADDLE_LAYER)
+    );
+
+    let right_barrier_collider_handle = colliders.insert(
+      Collider::new_static(arena_width, arena_height / 2.0, BARRIER_THICKNESS, arena_height)
+        .with_layer(BARRIER_LAYER, BALL_LAYER | PADDLE_LAYER)
+    );
+
+    let top_barrier_collider_handle = colliders.insert(
+      Collider::new_static(arena_width / 2.0, 0.0, arena_width, BARRIER_THICKNESS)
+        .with_layer(BARRIER_LAYER, BALL_LAYER | PADDLE_LAYER)
+    );
+
+    let bottom_barrier_collider_handle = colliders.insert(
+      Collider::new_static(arena_width / 2.0, arena_height, arena_width, BARRIER_THICKNESS)
+        .with_layer(BARRIER_LAYER, BALL_LAYER | PADDLE_LAYER)
+    );
+
+    let scoreboard = Scoreboard::new(rules);
+    let ball_velocity = serve_velocity(scoreboard.next_receiver(), &mut random);
+    let ai_error = random.range_f32(-MAX_AI_ERROR, MAX_AI_ERROR);
+
+    Self {
+      arena_width,
+      arena_height,
+      scoreboard,
+      previous_ball_transform: ball_transform.clone(),
+      ball_transform,
+      ball_velocity,
+      previous_left_paddle_transform: left_paddle_transform.clone(),
+      left_paddle_transform,
+      left_paddle_velocity: Vector2::ZERO,
+      previous_right_paddle_transform: right_paddle_transform.clone(),
+      right_paddle_transform,
+      right_paddle_velocity: Vector2::ZERO,
+      power_up_transforms,
+      colliders,
+      collision_tracker: CollisionTracker::new(),
+      collision_events: vec![],
+      collisions: vec![],
+      power_up_collider_handles,
+      ball_collider_handle,
+      left_paddle_collider_handle,
+      right_paddle_collider_handle,
+      left_barrier_collider_handle,
+      right_barrier_collider_handle,
+      top_barrier_collider_handle,
+      bottom_barrier_collider_handle,
+      random,
+      ai_error,
+      returned_by: None
+    }
+  }
+
+  pub fn seed(&self) -> u64 {
+    self.random.seed()
+  }
+
+  pub fn scoreboard(&self) -> &Scoreboard {
+    &self.scoreboard
+  }
+
+  pub fn ball_transform(&self) -> &Transform {
+    &self.ball_transform
+  }
+
+  pub fn ball_velocity(&self) -> Vector2 {
+    self.ball_velocity
+  }
+
+  pub fn left_paddle_transform(&self) -> &Transform {
+    &self.left_paddle_transform
+  }
+
+  pub fn right_paddle_transform(&self) -> &Transform {
+    &self.right_paddle_transform
+  }
+
+  pub fn interpolated_ball_transform(&self, alpha: f32) -> Transform {
+    Transform::interpolated(&self.previous_ball_transform, &self.ball_transform, alpha)
+  }
+
+  pub fn interpolated_left_paddle_transform(&self, alpha: f32) -> Transform {
+    Transform::interpolated(&self.previous_left_paddle_transform, &self.left_paddle_transform, alpha)
+  }
+
+  pub fn interpolated_right_paddle_transform(&self, alpha: f32) -> Transform {
+    Transform::interpolated(&self.previous_right_paddle_transform, &self.right_paddle_transform, alpha)
+  }
+
+  pub fn power_up_transforms(&self) -> &[Transform] {
+    &self.power_up_transforms
+  }
+
+  pub fn colliders(&self) -> &ColliderWorld {
+    &self.colliders
+  }
+
+  pub fn collision_events(&self) -> &[CollisionEvent] {
+    &self.collision_events
+  }
+
+  pub fn drain_collision_events(&mut self) -> Vec<CollisionEvent> {
+    std::mem::take(&mut self.collision_events)
+  }
+
+  pub fn collisions(&self) -> &[Collision] {
+    &self.collisions
+  }
+
+  pub fn collider_velocities(&self) -> Vec<(ColliderHandle, Vector2)> {
+    let ball_velocity = if self.scoreboard.is_ball_in_play() {
+      self.ball_velocity
+    } else {
+      Vector2::ZERO
+    };
+
+    vec![
+      (self.ball_collider_handle, ball_velocity),
+      (self.left_paddle_collider_handle, self.left_paddle_velocity),
+      (self.right_paddle_collider_handle, self.right_paddle_velocity)
+    ]
+  }
+
+  pub fn predict_ball_arrival_y(&self) -> Option<f32> {
+    let arrival_x = self.right_paddle_transform.x() - ((PADDLE_WIDTH + BALL_SIZE) / 2.0);
+    let mut origin = self.ball_transform.position();
+    let mut direction = self.ball_velocity;
+
+    if direction.x <= 0.0 || origin.x > arrival_x {
+      return None;
+    }
+
+    for _ in 0..MAX_PREDICTED_BOUNCES {
+      let hit = aabb_cast(&self.colliders, origin, BALL_SIZE, BALL_SIZE, direction, f32::INFINITY, BARRIER_LAYER)?;
+
+      if hit.point().x >= arrival_x {
+        return Some(origin.y + ((arrival_x - origin.x) * (direction.y / direction.x)));
+      }
+
+      if hit.normal().y == 0.0 {
+        return None;
+      }
+
+      origin = hit.point();
+      direction = direction.reflect(hit.normal());
+    }
+
+    None
+  }
+
+  pub fn restart_match(&mut self) {
+    for power_up_collider_handle in self.power_up_collider_handles.drain(..) {
+      self.colliders.remove(power_up_collider_handle);
+    }
+
+    self.power_up_transforms = place_power_ups(self.arena_width, self.arena_height, &mut self.random);
+    self.power_up_collider_handles = insert_power_up_colliders(&mut self.colliders, &self.power_up_transforms);
+
+    self.scoreboard.reset();
+    self.serve(self.scoreboard.next_receiver());
+  }
+
+  pub fn step(&mut self, deltamillis: f32, inputs: &Inputs) -> Vec<MatchEvent> {
+    let mut events = vec![];
+
+    self.previous_ball_transform = self.ball_transform.clone();
+    self.previous_left_paddle_transform = self.left_paddle_transform.clone();
+    self.previous_right_paddle_transform = self.right_paddle_transform.clone();
+
+    if let Some(event) = self.scoreboard.update(deltamillis) {
+      if let MatchEvent::BallServed { receiver } = event {
+        self.serve(receiver);
+      }
+
+      events.push(event);
+    }
+
+    self.left_paddle_velocity.y = inputs.left_paddle_direction() * PADDLE_SPEED;
+    self.update_right_paddle_ai();
+
+    let mut scorer = None;
+    let mut swept_contacts = vec![];
+    let mut remaining_time = 1.0;
+
+    for _ in 0..MAX_BALL_SWEEPS {
+      let ball_translation = self.ball_translation(deltamillis * remaining_time);
+      let left_paddle_translation = self.left_paddle_velocity * deltamillis * remaining_time;
+      let right_paddle_translation = self.right_paddle_velocity * deltamillis * remaining_time;
+
+      self.sync_colliders();
+
+      let time_of_impact = match self.find_ball_impact(ball_translation, left_paddle_translation, right_paddle_translation) {
+        Some(impact) => {
+          self.ball_transform.translate(ball_translation * impact.time_of_impact());
+          self.left_paddle_transform.translate(left_paddle_translation * impact.time_of_impact());
+          self.right_paddle_transform.translate(right_paddle_translation * impact.time_of_impact());
+
+          swept_contacts.extend(self.swept_contact(&impact));
+
+          let resolution = resolve_swept_collision(&impact, self.ball_velocity, BALL_RESTITUTION);
+          scorer = self.hit_ball(impact.secondary_handle(), resolution);
+
+          impact.time_of_impact()
+        },
+
+        None => {
+          self.ball_transform.translate(ball_translation);
+          self.left_paddle_transform.translate(left_paddle_translation);
+          self.right_paddle_transform.translate(right_paddle_translation);
+
+          1.0
+        }
+      };
+
+      remaining_time *= 1.0 - time_of_impact;
+
+      if scorer.is_some() || remaining_time <= 0.0 {
+        break;
+      }
+    }
+
+    if remaining_time > 0.0 {
+      self.left_paddle_transform.translate(self.left_paddle_velocity * deltamillis * remaining_time);
+      self.right_paddle_transform.translate(self.right_paddle_velocity * deltamillis * remaining_time);
+    }
+
+    self.sync_colliders();
+
+    if let Some(collision_scorer) = self.resolve_collisions(&swept_contacts) {
+      scorer = Some(collision_scorer);
+    }
+
+    if let Some(player) = self.returned_by.take() {
+      events.push(MatchEvent::BallReturned { player });
+    }
+
+    if let Some(scorer) = scorer {
+      events.append(&mut self.scoreboard.award_point(scorer));
+      self.center_ball();
+    }
+
+    events
+  }
+
+  fn ball_translation(&self, deltamillis: f32) -> Vector2 {
+    if !self.scoreboard.is_ball_in_play() {
+      return Vector2::ZERO;
+    }
+
+    self.ball_velocity * deltamillis
+  }
+
+  fn serve(&mut self, receiver: Player) {
+    self.center_ball();
+    self.ball_velocity = serve_velocity(receiver, &mut self.random);
+    self.roll_ai_error();
+  }
+
+  fn roll_ai_error(&mut self) {
+    self.ai_error = self.random.range_f32(-MAX_AI_ERROR, MAX_AI_ERROR);
+  }
+
+  fn center_ball(&mut self) {
+    self.ball_transform.set_position(Vector2::new(self.arena_width / 2.0, self.arena_height / 2.0));
+    self.previous_ball_transform = self.ball_transform.clone();
+    self.sync_colliders();
+  }
+
+  fn sync_colliders(&mut self) {
+    if let Some(collider) = self.colliders.get_mut(self.ball_collider_handle) {
+      collider.set_transform(&self.ball_transform);
+    }
+
+    if let Some(collider) = self.colliders.get_mut(self.left_paddle_collider_handle) {
+      collider.set_transform(&self.left_paddle_transform);
+    }
+
+    if let Some(collider) = self.colliders.get_mut(self.right_paddle_collider_handle) {
+      collider.set_transform(&self.right_paddle_transform);
+    }
+  }
+
+  fn find_ball_impact(&self, ball_translation: Vector2, left_paddle_translation: Vector2, right_paddle_translation: Vector2) -> Option<SweptCollision> {
+    let obstacles = [
+      (self.left_paddle_collider_handle, left_paddle_translation),
+      (self.right_paddle_collider_handle, right_paddle_translation),
+      (self.left_barrier_collider_handle, Vector2::ZERO),
+      (self.right_barrier_collider_handle, Vector2::ZERO),
+      (self.top_barrier_collider_handle, Vector2::ZERO),
+      (self.bottom_barrier_collider_handle, Vector2::ZERO)
+    ];
+
+    let ball_collider = self.colliders.get(self.ball_collider_handle)?;
+    let mut earliest_impact: Option<SweptCollision> = None;
+
+    for (obstacle_handle, obstacle_translation) in obstacles {
+      let obstacle_collider = match self.colliders.get(obstacle_handle) {
+        Some(obstacle_collider) => obstacle_collider,
+        None => continue
+      };
+
+      let impact = find_swept_collision(
+        self.ball_collider_handle,
+        obstacle_handle,
+        ball_collider,
+        ball_translation,
+        obstacle_collider,
+        obstacle_translation
+      );
+
+      if let Some(impact) = impact {
+        let is_earlier = match &earliest_impact {
+          Some(earliest_impact) => impact.time_of_impact() < earliest_impact.time_of_impact(),
+          None => true
+        };
+
+        if is_earlier {
+          earliest_impact = Some(impact);
+        }
+      }
+    }
+
+    earliest_impact
+  }
+
+  fn update_right_paddle_ai(&mut self) {
+    if self.ball_velocity.x < 0.0 || !self.scoreboard.is_ball_in_play() {
+      self.right_paddle_velocity.y = 0.0;
+    } else {
+      let target_y = self.predict_ball_arrival_y().unwrap_or(self.ball_transform.y()) + self.ai_error;
+
+      if self.right_paddle_transform.y() < target_y {
+        self.right_paddle_velocity.y = PADDLE_SPEED;
+      }
+
+      if self.right_paddle_transform.y() > target_y {
+        self.right_paddle_velocity.y = -PADDLE_SPEED;
+      }
+    }
+  }
+
+  fn swept_contact(&self, impact: &SweptCollision) -> Option<Collision> {
+    let primary = self.colliders.get(impact.primary_handle())?;
+    let secondary = self.colliders.get(impact.secondary_handle())?;
+
+    Some(Collision::new(
+      impact.primary_handle(),
+      impact.secondary_handle(),
+      primary.layer(),
+      secondary.layer(),
+      impact.normal(),
+      impact.entry_direction(),
+      0.0
+    ))
+  }
+
+  fn resolve_collisions(&mut self, swept_contacts: &[Collision]) -> Option<Player> {
+    let mut scorer = None;
+
+    let collisions = find_collisions(&self.colliders).unwrap_or_default();
+    let contacts: Vec<Collision> = collisions.iter().chain(swept_contacts).copied().collect();
+    let events = self.collision_tracker.update(&contacts);
+
+    self.collect_power_ups(&events);
+    self.collision_events.extend(events);
+
+    for collision in &collisions {
+      if collision.primary_layer().contains(BALL_LAYER) {
+        let resolution = resolve_collision(collision, self.ball_velocity, BALL_RESTITUTION);
+        let ball_scorer = self.hit_ball(collision.secondary_handle(), resolution);
+
+        if ball_scorer.is_some() {
+          scorer = ball_scorer;
+        }
+      }
+
+      if collision.primary_layer().contains(PADDLE_LAYER) && collision.secondary_layer().contains(BARRIER_LAYER) {
+        let push_out = resolve_collision(collision, Vector2::ZERO, 0.0).translation();
+
+        if collision.primary_handle() == self.left_paddle_collider_handle {
+          self.left_paddle_transform.translate(push_out);
+        }
+
+        if collision.primary_handle() == self.right_paddle_collider_handle {
+          self.right_paddle_transform.translate(push_out);
+        }
+      }
+    }
+
+    self.collisions = collisions;
+
+    scorer
+  }
+
+  fn collect_power_ups(&mut self, events: &[CollisionEvent]) {
+    for event in events {
+      if event.phase() != CollisionPhase::Enter || event.primary_handle() != self.ball_collider_handle {
+        continue;
+      }
+
+      let power_up_index = self.power_up_collider_handles
+        .iter()
+        .position(|power_up_collider_handle| *power_up_collider_handle == event.secondary_handle());
+
+      if let Some(power_up_index) = power_up_index {
+        self.colliders.remove(event.secondary_handle());
+        self.power_up_collider_handles.remove(power_up_index);
+        self.power_up_transforms.remove(power_up_index);
+      }
+    }
+  }
+
+  fn hit_ball(&mut self, secondary_handle: ColliderHandle, resolution: CollisionResolution) -> Option<Player> {
+    if secondary_handle == self.left_barrier_collider_handle {
+      return Some(Player::Right);
+    }
+
+    if secondary_handle == self.right_barrier_collider_handle {
+      return Some(Player::Left);
+    }
+
+    let paddle_center = if secondary_handle == self.left_paddle_collider_handle {
+      Some(self.left_paddle_transform.position())
+    } else if secondary_handle == self.right_paddle_collider_handle {
+      Some(self.right_paddle_transform.position())
+    } else {
+      None
+    };
+
+    let is_barrier = secondary_handle == self.top_barrier_collider_handle
+    || secondary_handle == self.bottom_barrier_collider_handle;
+
+    if paddle_center.is_none() && !is_barrier {
+      return None;
+    }
+
+    self.ball_transform.translate(resolution.translation());
+    self.ball_velocity = resolution.velocity();
+
+    if secondary_handle == self.left_paddle_collider_handle {
+      self.roll_ai_error();
+    }
+
+    if let Some(paddle_center) = paddle_center
+    && resolution.normal().y == 0.0
+    && resolution.normal().x * ((self.arena_width / 2.0) - paddle_center.x) > 0.0 {
+      self.returned_by = Some(if secondary_handle == self.left_paddle_collider_handle { Player::Left } else { Player::Right });
+
+      self.ball_velocity = deflect_from_paddle(
+        resolution.normal(),
+        self.ball_transform.y() - paddle_center.y,
+        PADDLE_HEIGHT / 2.0,
+        MAX_DEFLECTION_ANGLE
+      ) * resolution.velocity().length();
+    }
+
+    None
+  }
+}
+
+fn place_power_ups(arena_width: f32, arena_height: f32, random: &mut Random) -> Vec<Transform> {
+  let spawn_area = Rect::from_corners(
+    Vector2::new(arena_width / 4.0, POWER_UP_MARGIN),
+    Vector2::new((arena_width / 4.0) * 3.0, arena_height - POWER_UP_MARGIN)
+  );
+  let serve_spot = Rect::from_center(Vector2::new(arena_width / 2.0, arena_height / 2.0), BALL_SIZE, BALL_SIZE);
+  let mut placed: Vec<Rect> = vec![];
+
+  for _ in 0..MAX_POWER_UP_PLACEMENT_ATTEMPTS {
+    if placed.len() == POWER_UP_COUNT {
+      break;
+    }
+
+    let candidate = Rect::from_center(
+      Vector2::new(
+        random.range_f32(spawn_area.left(), spawn_area.right()),
+        random.range_f32(spawn_area.top(), spawn_area.bottom())
+      ),
+      POWER_UP_SIZE,
+      POWER_UP_SIZE
+    );
+
+    if candidate.intersects(&serve_spot) || placed.iter().any(|power_up| power_up.intersects(&candidate)) {
+      continue;
+    }
+
+    placed.push(candidate);
+  }
+
+  if placed.len() < POWER_UP_COUNT {
+    placed = power_up_slots(&spawn_area)
+      .into_iter()
+      .filter(|slot| !slot.intersects(&serve_spot))
+      .take(POWER_UP_COUNT)
+      .collect();
+  }
+
+  placed
+    .iter()
+    .map(|power_up| Transform::new(power_up.center().x, power_up.center().y))
+    .collect()
+}
+
+fn insert_power_up_colliders(colliders: &mut ColliderWorld, power_up_transforms: &[Transform]) -> Vec<ColliderHandle> {
+  power_up_transforms
+    .iter()
+    .map(|power_up_transform| {
+      colliders.insert(
+        Collider::new_static(power_up_transform.x(), power_up_transform.y(), POWER_UP_SIZE, POWER_UP_SIZE)
+          .with_layer(POWER_UP_LAYER, BALL_LAYER)
+      )
+    })
+    .collect()
+}
+
+fn power_up_slots(spawn_area: &Rect) -> Vec<Rect> {
+  let columns = (spawn_area.width() / POWER_UP_SLOT_SPACING).floor().max(1.0) as usize;
+  let rows = (spawn_area.height() / POWER_UP_SLOT_SPACING).floor().max(1.0) as usize;
+  let origin = spawn_area.center() - Vector2::new(
+    (columns - 1) as f32 * POWER_UP_SLOT_SPACING / 2.0,
+    (rows - 1) as f32 * POWER_UP_SLOT_SPACING / 2.0
+  );
+
+  (0..rows)
+    .flat_map(|row| (0..columns).map(move |column| (row, column)))
+    .map(|(row, column)| {
+      Rect::from_center(
+        origin + Vector2::new(column as f32 * POWER_UP_SLOT_SPACING, row as f32 * POWER_UP_SLOT_SPACING),
+        POWER_UP_SIZE,
+        POWER_UP_SIZE
+      )
+    })
+    .collect()
+}
+
+fn serve_velocity(receiver: Player, random: &mut Random) -> Vector2 {
+  let angle = random.range_f32(-MAX_SERVE_ANGLE, MAX_SERVE_ANGLE);
+
+  let direction = match receiver {
+    Player::Left => Vector2::new(-angle.cos(), angle.sin()),
+    Player::Right => Vector2::new(angle.cos(), angle.sin())
+  };
+
+  direction * BALL_SPEED
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const ARENA_WIDTH: f32 = 800.0;
+  const ARENA_HEIGHT: f32 = 600.0;
+
+  #[test]
+  fn constructor() {
+    let simulation = Simulation::new(ARENA_WIDTH, ARENA_HEIGHT);
+
+    assert_eq!(simulation.ball_transform().x(), ARENA_WIDTH / 2.0);
+    assert_eq!(simulation.ball_transform().y(), ARENA_HEIGHT / 2.0);
+    assert_eq!(simulation.left_paddle_transform().x(), PADDLE_INSET);
+    assert_eq!(simulation.right_paddle_transform().x(), ARENA_WIDTH - PADDLE_INSET);
+    assert_eq!(simulation.power_up_transforms().len(), 4);
+    assert_eq!(simulation.colliders().len(), 11);
+  }
+
+  #[test]
+  fn ball_movement() {
+    let mut simulation = Simulation::new(ARENA_WIDTH, ARENA_HEIGHT);
+    let expected_translation = simulation.ball_velocity() * 10.0;
+
+    simulation.step(10.0, &Inputs::default());
+
+    assert_eq!(simulation.ball_transform().x(), (ARENA_WIDTH / 2.0) + expected_translation.x);
+    assert_eq!(simulation.ball_transform().y(), (ARENA_HEIGHT / 2.0) + expected_translation.y);
+  }
+
+  #[test]
+  fn debug_state() {
+    let mut simulation = Simulation::new(ARENA_WIDTH, ARENA_HEIGHT);
+    let inputs = Inputs { left_paddle_up: true, ..Inputs::default() };
+
+    for _ in 0..200 {
+      simulation.step(10.0, &inputs);
+    }
+
+    let velocities = simulation.collider_velocities();
+    assert_eq!(velocities.len(), 3);
+    assert!(velocities.iter().all(|(handle, _)| simulation.colliders().contains(*handle)));
+    assert_eq!(velocities[1].1, Vector2::new(0.0, -PADDLE_SPEED));
+
+    assert!(simulation.collisions().iter().any(|collision| {
+      collision.primary_layer().contains(PADDLE_LAYER) && collision.secondary_layer().contains(BARRIER_LAYER)
+    }));
+  }
+
+  #[test]
+  fn left_paddle_input() {
+    let mut simulation = Simulation::new(ARENA_WIDTH, ARENA_HEIGHT);
+    let inputs = Inputs { left_paddle_up: true, left_paddle_down: false };
+
+    simulation.step(10.0, &inputs);
+
+    assert_eq!(simulation.left_paddle_transform().y(), (ARENA_HEIGHT / 2.0) - (PADDLE_SPEED * 10.0));
+  }
+
+  #[test]
+  fn left_paddle_stops_at_barrier() {
+    let mut simulation = Simulation::new(ARENA_WIDTH, ARENA_HEIGHT);
+    let inputs = Inputs { left_paddle_up: true, left_paddle_down: false };
+
+    for _ in 0..1000 {
+      simulation.step(16.0, &inputs);
+    }
+
+    let paddle_top = simulation.left_paddle_transform().y() - (PADDLE_HEIGHT / 2.0);
+    assert_eq!(paddle_top, BARRIER_THICKNESS / 2.0);
+  }
+
+  #[test]
+  fn ball_stays_in_arena() {
+    let mut simulation = Simulation::new(ARENA_WIDTH, ARENA_HEIGHT);
+
+    for _ in 0..10000 {
+      simulation.step(16.0, &Inputs::default());
+
+      let ball_x = simulation.ball_transform().x();
+      let ball_y = simulation.ball_transform().y();
+
+      assert!(ball_x > 0.0 && ball_x < ARENA_WIDTH);
+      assert!(ball_y > 0.0 && ball_y < ARENA_HEIGHT);
+    }
+  }
+
+  #[test]
+  fn right_paddle_follows_ball() {
+    let mut simulation = Simulation::new(ARENA_WIDTH, ARENA_HEIGHT);
+    let target_y = simulation.predict_ball_arrival_y().unwrap() + simulation.ai_error;
+    let initial_distance = (target_y - simulation.right_paddle_transform().y()).abs();
+
+    for _ in 0..10 {
+      simulation.step(16.0, &Inputs::default());
+    }
+
+    let distance = (target_y - simulation.right_paddle_transform().y()).abs();
+
+    assert!(initial_distance > PADDLE_SPEED * 16.0);
+    assert!(distance < initial_distance);
+  }
+
+  #[test]
+  fn right_paddle_deflects_ball() {
+    let mut simulation = Simulation::new(ARENA_WIDTH, ARENA_HEIGHT);
+
+    let mut events = vec![];
+
+    for _ in 0..1000 {
+      events = simulation.step(16.0, &Inputs::default());
+
+      if simulation.ball_velocity().x < 0.0 {
+        break;
+      }
+
+      assert!(events.is_empty());
+    }
+
+    assert_eq!(events, vec![MatchEvent::BallReturned { player: Player::Right }]);
+
+    let velocity = simulation.ball_velocity();
+    let speed = velocity.length();
+
+    assert!(velocity.x < 0.0);
+    assert!(velocity.y.abs() <= MAX_DEFLECTION_ANGLE.sin() * speed);
+    assert!((speed - (BALL_SPEED * BALL_RESTITUTION)).abs() < 0.0001);
+    assert!(simulation.ball_transform().x() < simulation.right_paddle_transform().x());
+  }
+
+  #[test]
+  fn ball_arrival_prediction() {
+    let mut simulation = Simulation::new(ARENA_WIDTH, ARENA_HEIGHT);
+    let predicted_y = simulation.predict_ball_arrival_y().unwrap();
+    let arrival_x = simulation.right_paddle_transform().x() - ((PADDLE_WIDTH + BALL_SIZE) / 2.0);
+
+    while simulation.ball_transform().x() < arrival_x - 1.0 {
+      simulation.step(1.0, &Inputs::default());
+    }
+
+    assert!((simulation.ball_transform().y() - predicted_y).abs() < 1.0);
+
+    simulation.step(100.0, &Inputs::default());
+    assert!(simulation.predict_ball_arrival_y().is_none());
+  }
+
+  #[test]
+  fn point_scored() {
+    let mut simulation = Simulation::new(ARENA_WIDTH, ARENA_HEIGHT);
+    let mut point_events = vec![];
+
+    for _ in 0..10000 {
+      let events: Vec<MatchEvent> = simulation
+        .step(16.0, &Inputs::default())
+        .into_iter()
+        .filter(|event| !matches!(event, MatchEvent::BallReturned { .. }))
+        .collect();
+
+      if !events.is_empty() {
+        point_events = events;
+        break;
+      }
+    }
+
+    let scorer = match point_events.first() {
+      Some(MatchEvent::PointScored { scorer, .. }) => *scorer,
+      _ => panic!("No Point Scored")
+    };
+
+    assert_eq!(simulation.scoreboard().score(scorer), 1);
+    assert_eq!(simulation.scoreboard().score(scorer.opponent()), 0);
+    assert_eq!(simulation.ball_transform().x(), ARENA_WIDTH / 2.0);
+    assert_eq!(simulation.ball_transform().y(), ARENA_HEIGHT / 2.0);
+    assert!(!simulation.scoreboard().is_ball_in_play());
+  }
+
+  #[test]
+  fn ball_served_after_point() {
+    let rules = MatchRules { serve_delay: 100.0, ..MatchRules::default() };
+    let mut simulation = Simulation::with_rules(ARENA_WIDTH, ARENA_HEIGHT, rules);
+    let mut served = false;
+
+    for _ in 0..10000 {
+      let events = simulation.step(16.0, &Inputs::default());
+
+      if events.iter().any(|event| matches!(event, MatchEvent::BallServed { .. })) {
+        served = true;
+        break;
+      }
+    }
+
+    assert!(served);
+    assert!(simulation.scoreboard().is_ball_in_play());
+  }
+
+  #[test]
+  fn match_played_to_completion() {
+    let rules = MatchRules { target_score: 3, win_by: 1, serve_delay: 100.0, ..MatchRules::default() };
+    let mut simulation = Simulation::with_rules(ARENA_WIDTH, ARENA_HEIGHT, rules);
+    let mut winner = None;
+
+    for _ in 0..100000 {
+      for event in simulation.step(16.0, &Inputs::default()) {
+        if let MatchEvent::MatchWon { winner: match_winner } = event {
+          winner = Some(match_winner);
+        }
+      }
+
+      if winner.is_some() {
+        break;
+      }
+    }
+
+    let winner = winner.expect("Match Never Finished");
+    assert_eq!(simulation.scoreboard().score(winner), 3);
+
+    simulation.step(16.0, &Inputs::default());
+    assert_eq!(simulation.ball_transform().x(), ARENA_WIDTH / 2.0);
+
+    simulation.restart_match();
+    assert_eq!(simulation.scoreboard().score(winner), 0);
+    assert!(simulation.scoreboard().is_ball_in_play());
+  }
+
+  #[test]
+  fn interpolated_transforms() {
+    let mut simulation = Simulation::new(ARENA_WIDTH, ARENA_HEIGHT);
+    let inputs = Inputs { left_paddle_up: true, left_paddle_down: false };
+
+    simulation.step(10.0, &inputs);
+
+    let previous_ball = simulation.interpolated_ball_transform(0.0);
+    let current_ball = simulation.interpolated_ball_transform(1.0);
+    let middle_paddle = simulation.interpolated_left_paddle_transform(0.5);
+
+    assert_eq!(previous_ball.x(), ARENA_WIDTH / 2.0);
+    assert_eq!(previous_ball.y(), ARENA_HEIGHT / 2.0);
+    assert_eq!(current_ball.x(), simulation.ball_transform().x());
+    assert_eq!(current_ball.y(), simulation.ball_transform().y());
+    assert_eq!(middle_paddle.y(), (ARENA_HEIGHT / 2.0) - (PADDLE_SPEED * 5.0));
+  }
+
+  #[test]
+  fn seeded_randomness() {
+    let first = Simulation::with_seed(ARENA_WIDTH, ARENA_HEIGHT, MatchRules::default(), 1);
+    let second = Simulation::with_seed(ARENA_WIDTH, ARENA_HEIGHT, MatchRules::default(), 1);
+    let third = Simulation::with_seed(ARENA_WIDTH, ARENA_HEIGHT, MatchRules::default(), 2);
+
+    assert_eq!(first.seed(), 1);
+    assert_eq!(first.ball_velocity(), second.ball_velocity());
+    assert_ne!(first.ball_velocity(), third.ball_velocity());
+
+    for (first_power_up, second_power_up) in first.power_up_transforms().iter().zip(second.power_up_transforms()) {
+      assert_eq!(first_power_up.position(), second_power_up.position());
+    }
+
+    assert_ne!(first.power_up_transforms()[0].position(), third.power_up_transforms()[0].position());
+
+    for power_up_transform in third.power_up_transforms() {
+      assert!(power_up_transform.x() >= ARENA_WIDTH / 4.0 && power_up_transform.x() <= (ARENA_WIDTH / 4.0) * 3.0);
+      assert!(power_up_transform.y() >= POWER_UP_MARGIN && power_up_transform.y() <= ARENA_HEIGHT - POWER_UP_MARGIN);
+    }
+  }
+
+  #[test]
+  fn power_ups_placed_apart() {
+    let serve_spot = Rect::from_center(Vector2::new(ARENA_WIDTH / 2.0, ARENA_HEIGHT / 2.0), BALL_SIZE, BALL_SIZE);
+
+    for seed in 0..200 {
+      let power_ups: Vec<Rect> = place_power_ups(ARENA_WIDTH, ARENA_HEIGHT, &mut Random::new(seed))
+        .iter()
+        .map(|transform| Rect::from_center(transform.position(), POWER_UP_SIZE, POWER_UP_SIZE))
+        .collect();
+
+      assert_eq!(power_ups.len(), POWER_UP_COUNT);
+
+      for (index, power_up) in power_ups.iter().enumerate() {
+        assert!(!power_up.intersects(&serve_spot));
+        assert!(power_ups[index + 1..].iter().all(|other| !other.intersects(power_up)));
+      }
+    }
+  }
+
+  #[test]
+  fn small_arena_power_ups() {
+    let simulation = Simulation::new(100.0, 100.0);
+    let arena = Rect::from_corners(Vector2::new(0.0, 0.0), Vector2::new(simulation.arena_width, simulation.arena_height));
+
+    assert_eq!(simulation.power_up_transforms().len(), POWER_UP_COUNT);
+    assert!(simulation.power_up_transforms().iter().all(|transform| arena.contains_point(transform.position())));
+
+    for seed in 0..200 {
+      let power_ups = place_power_ups(MIN_ARENA_WIDTH, MIN_ARENA_HEIGHT, &mut Random::new(seed));
+
+      assert_eq!(power_ups.len(), POWER_UP_COUNT);
+      assert!(power_ups.iter().all(|transform| {
+        let position = transform.position();
+
+        position.x >= MIN_ARENA_WIDTH / 4.0
+        && position.x <= (MIN_ARENA_WIDTH / 4.0) * 3.0
+        && position.y >= POWER_UP_MARGIN
+        && position.y <= MIN_ARENA_HEIGHT - POWER_UP_MARGIN
+      }));
+    }
+  }
+
+  #[test]
+  fn power_up_slot_fallback() {
+    let spawn_area = Rect::from_corners(Vector2::new(0.0, 0.0), Vector2::new(96.0, 96.0));
+    let slots = power_up_slots(&spawn_area);
+
+    assert_eq!(slots.len(), 9);
+    assert!(slots.iter().all(|slot| spawn_area.contains_point(slot.min()) && spawn_area.contains_point(slot.max())));
+  }
+
+  #[test]
+  fn serve_angle_within_limits() {
+    let mut random = Random::new(3);
+
+    for _ in 0..100 {
+      let velocity = serve_velocity(Player::Left, &mut random);
+
+      assert!(velocity.x < 0.0);
+      assert!(velocity.y.abs() <= MAX_SERVE_ANGLE.sin() * BALL_SPEED);
+      assert!((velocity.length() - BALL_SPEED).abs() < 0.0001);
+    }
+  }
+
+  #[test]
+  fn ball_keeps_resolved_speed() {
+    let mut simulation = Simulation::new(ARENA_WIDTH, ARENA_HEIGHT);
+    let resolution = CollisionResolution::new(Vector2::ZERO, Vector2::new(0.15, 0.2), Vector2::new(0.0, 1.0));
+
+    simulation.hit_ball(simulation.top_barrier_collider_handle, resolution);
+    let position = simulation.ball_transform().position();
+
+    simulation.step(10.0, &Inputs::default());
+
+    assert!((simulation.ball_transform().x() - (position.x + 1.5)).abs() < 0.0001);
+    assert!((simulation.ball_transform().y() - (position.y + 2.0)).abs() < 0.0001);
+  }
+
+  #[test]
+  fn deterministic_steps() {
+    let mut first = Simulation::new(ARENA_WIDTH, ARENA_HEIGHT);
+    let mut second = Simulation::new(ARENA_WIDTH, ARENA_HEIGHT);
+    let inputs = Inputs { left_paddle_up: false, left_paddle_down: true };
+
+    for _ in 0..5000 {
+      first.step(1000.0 / 120.0, &inputs);
+      second.step(1000.0 / 120.0, &inputs);
+    }
+
+    assert_eq!(first.ball_transform().x(), second.ball_transform().x());
+    assert_eq!(first.ball_transform().y(), second.ball_transform().y());
+    assert_eq!(first.scoreboard().score(Player::Left), second.scoreboard().score(Player::Left));
+    assert_eq!(first.scoreboard().score(Player::Right), second.scoreboard().score(Player::Right));
+    assert_eq!(first.right_paddle_transform().y(), second.right_paddle_transform().y());
+  }
+
+  #[test]
+  fn large_steps_stay_in_arena() {
+    let mut simulation = Simulation::new(ARENA_WIDTH, ARENA_HEIGHT);
+
+    for _ in 0..1000 {
+      simulation.step(100.0, &Inputs::default());
+
+      let ball_x = simulation.ball_transform().x();
+      let ball_y = simulation.ball_transform().y();
+
+      assert!(ball_x > 0.0 && ball_x < ARENA_WIDTH);
+      assert!(ball_y > 0.0 && ball_y < ARENA_HEIGHT);
+    }
+  }
+
+  #[test]
+  fn bounce_keeps_remaining_motion() {
+    let mut simulation = Simulation::new(ARENA_WIDTH, ARENA_HEIGHT);
+
+    simulation.ball_transform.set_position(Vector2::new(ARENA_WIDTH / 2.0, 20.0));
+    simulation.ball_velocity = Vector2::new(0.3, -0.4);
+
+    simulation.step(40.0, &Inputs::default());
+
+    assert!((simulation.ball_transform().x() - ((ARENA_WIDTH / 2.0) + 12.0)).abs() < 0.001);
+    assert!((simulation.ball_transform().y() - 20.0).abs() < 0.001);
+    assert!(simulation.ball_velocity().y > 0.0);
+  }
+
+  #[test]
+  fn power_up_pickup_removes_collider() {
+    let mut simulation = Simulation::new(ARENA_WIDTH, ARENA_HEIGHT);
+    let inputs = Inputs { left_paddle_up: false, left_paddle_down: true };
+
+    for _ in 0..100000 {
+      simulation.drain_collision_events();
+      simulation.step(16.0, &inputs);
+
+      if simulation.power_up_transforms().len() < 4 {
+        break;
+      }
+    }
+
+    assert_eq!(simulation.power_up_transforms().len(), 3);
+    assert_eq!(simulation.colliders().len(), 10);
+
+    let pickup_events: Vec<&CollisionEvent> = simulation
+      .collision_events()
+      .iter()
+      .filter(|event| event.phase() == CollisionPhase::Enter && simulation.colliders().get(event.secondary_handle()).is_none())
+      .collect();
+
+    assert_eq!(pickup_events.len(), 1);
+    assert_eq!(pickup_events[0].primary_handle(), simulation.ball_collider_handle);
+
+    simulation.drain_collision_events();
+    simulation.step(16.0, &inputs);
+
+    assert!(simulation.collision_events().iter().any(|event| event.phase() == CollisionPhase::Exit));
+  }
+
+  #[test]
+  fn restart_respawns_power_ups() {
+    let mut simulation = Simulation::new(ARENA_WIDTH, ARENA_HEIGHT);
+    let inputs = Inputs { left_paddle_up: false, left_paddle_down: true };
+
+    for _ in 0..100000 {
+      simulation.drain_collision_events();
+      simulation.step(16.0, &inputs);
+
+      if simulation.power_up_transforms().len() < POWER_UP_COUNT {
+        break;
+      }
+    }
+
+    assert_eq!(simulation.power_up_transforms().len(), POWER_UP_COUNT - 1);
+
+    simulation.restart_match();
+
+    assert_eq!(simulation.power_up_transforms().len(), POWER_UP_COUNT);
+    assert_eq!(simulation.colliders().len(), 11);
+
+    for (power_up_transform, power_up_collider_handle) in simulation.power_up_transforms().iter().zip(&simulation.power_up_collider_handles) {
+      let collider = simulation.colliders().get(*power_up_collider_handle).unwrap();
+
+      assert_eq!(collider.position(), power_up_transform.position());
+    }
+  }
+
+  #[test]
+  fn swept_hit_enters_collision() {
+    let mut simulation = Simulation::new(ARENA_WIDTH, ARENA_HEIGHT);
+
+    for _ in 0..1000 {
+      simulation.step(16.0, &Inputs::default());
+
+      if simulation.ball_velocity().x < 0.0 {
+        break;
+      }
+    }
+
+    assert!(simulation.collision_events().iter().any(|event| {
+      event.phase() == CollisionPhase::Enter
+      && event.primary_handle() == simulation.ball_collider_handle
+      && event.secondary_handle() == simulation.right_paddle_collider_handle
+    }));
+  }
+
+  #[test]
+  fn collision_events_accumulate_until_drained() {
+    let mut simulation = Simulation::new(ARENA_WIDTH, ARENA_HEIGHT);
+    let inputs = Inputs { left_paddle_up: true, left_paddle_down: false };
+
+    for _ in 0..1000 {
+      simulation.step(16.0, &inputs);
+    }
+
+    let events = simulation.drain_collision_events();
+
+    assert!(events.iter().any(|event| event.phase() == CollisionPhase::Enter));
+    assert!(events.iter().any(|event| event.phase() == CollisionPhase::Stay));
+    assert!(simulation.collision_events().is_empty());
+  }
+}
\ No newline at end of file
@@ -0,0 +1,70 @@
+use crate::prelude::{Rect, Vector2};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Sprite {
+  uv: Rect,
+  width: f32,
+  height: f32
+}
+
+impl Sprite {
+  pub fn new(uv: Rect, width: f32, height: f32) -> Self {
+    Self { uv, width, height }
+  }
+
+  pub fn full(width: f32, height: f32) -> Self {
+    Self::new(Rect::from_corners(Vector2::ZERO, Vector2::new(1.0, 1.0)), width, height)
+  }
+
+  pub fn uv(&self) -> Rect {
+    self.uv
+  }
+
+  pub fn width(&self) -> f32 {
+    self.width
+  }
+
+  pub fn height(&self) -> f32 {
+    self.height
+  }
+
+  pub fn split_grid(&self, columns: u32, rows: u32) -> Vec<Sprite> {
+    let cell_uv_size = Vector2::new(self.uv.width() / columns as f32, self.uv.height() / rows as f32);
+    let cell_width = self.width / columns as f32;
+    let cell_height = self.height / rows as f32;
+
+    (0..rows)
+      .flat_map(|row| (0..columns).map(move |column| (column, row)))
+      .map(|(column, row)| {
+        let cell_min = self.uv.min() + Vector2::new(cell_uv_size.x * column as f32, cell_uv_size.y * row as f32);
+        Sprite::new(Rect::from_corners(cell_min, cell_min + cell_uv_size), cell_width, cell_height)
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn full_texture() {
+    let sprite = Sprite::full(16.0, 128.0);
+
+    assert_eq!(sprite.uv().min(), Vector2::ZERO);
+    assert_eq!(sprite.uv().max(), Vector2::new(1.0, 1.0));
+    assert_eq!(sprite.width(), 16.0);
+    assert_eq!(sprite.height(), 128.0);
+  }
+
+  #[test]
+  fn grid_splitting() {
+    let sheet = Sprite::new(Rect::from_corners(Vector2::new(0.5, 0.0), Vector2::new(1.0, 0.5)), 64.0, 32.0);
+
+    let cells = sheet.split_grid(2, 2);
+
+    assert_eq!(cells.len(), 4);
+    assert_eq!(cells[1], Sprite::new(Rect::from_corners(Vector2::new(0.75, 0.0), Vector2::new(1.0, 0.25)), 32.0, 16.0));
+    assert_eq!(cells[2].uv().min(), Vector2::new(0.5, 0.25));
+  }
+}
\ No newline at end of file
@@ -0,0 +1,204 @@
+use crate::prelude::{
+  BitmapFont,
+  BufferObject,
+  Color,
+  Matrix3,
+  Matrix4,
+  ShaderProgram,
+  Sprite,
+  Texture,
+  TextStyle,
+  Transform,
+  Vector2,
+  VertexArray,
+  generate_batch_element_data,
+  generate_sprite_vertex_data,
+  set_model_matrix
+};
+
+const FLOATS_PER_SPRITE: usize = 32;
+const ELEMENTS_PER_SPRITE: usize = 6;
+
+struct BatchedSprite {
+  texture_id: gl::types::GLuint,
+  layer: i32,
+  vertex_data: [f32; FLOATS_PER_SPRITE]
+}
+
+#[derive(Debug, PartialEq)]
+struct DrawCall {
+  texture_id: gl::types::GLuint,
+  first_sprite: usize,
+  sprite_count: usize
+}
+
+pub struct SpriteBatch {
+  capacity: usize,
+  sprites: Vec<BatchedSprite>,
+  vertex_data: Vec<f32>,
+  vertex_buffer: BufferObject,
+  _element_buffer: BufferObject,
+  vertex_array: VertexArray
+}
+
+impl SpriteBatch {
+  pub fn new(capacity: usize) -> Result<Self, String> {
+    if capacity == 0 {
+      return Err("Sprite batch capacity must be greater than zero".to_string());
+    }
+
+    let vertex_buffer = BufferObject::dynamic_vertex_array(capacity * FLOATS_PER_SPRITE);
+    let element_buffer = BufferObject::element_array(generate_batch_element_data(capacity));
+    let vertex_array = VertexArray::textured_colored(&vertex_buffer, &element_buffer);
+
+    Ok(
+      Self {
+        capacity,
+        sprites: Vec::with_capacity(capacity),
+        vertex_data: Vec::with_capacity(capacity * FLOATS_PER_SPRITE),
+        vertex_buffer,
+        _element_buffer: element_buffer,
+        vertex_array
+      }
+    )
+  }
+
+  pub fn draw(&mut self, texture: &Texture, sprite: &Sprite, transform: &Transform, layer: i32) {
+    self.draw_colored(texture, sprite, transform, Color::WHITE, layer);
+  }
+
+  pub fn draw_colored(&mut self, texture: &Texture, sprite: &Sprite, transform: &Transform, color: Color, layer: i32) {
+    self.push(texture, sprite, &transform.affine(), color, layer);
+  }
+
+  pub fn draw_text(&mut self, texture: &Texture, font: &BitmapFont, text: &str, position: Vector2, style: &TextStyle, layer: i32) {
+    for (glyph, center) in font.layout(text, position, style) {
+      self.push(texture, &glyph, &Matrix3::translation(center.x, center.y), style.color, layer);
+    }
+  }
+
+  fn push(&mut self, texture: &Texture, sprite: &Sprite, matrix: &Matrix3, color: Color, layer: i32) {
+    self.sprites.push(BatchedSprite {
+      texture_id: texture.id(),
+      layer,
+      vertex_data: generate_sprite_vertex_data(matrix, sprite.width(), sprite.height(), sprite.uv(), color)
+    });
+  }
+
+  pub fn flush(&mut self, shader_program: &ShaderProgram) -> Result<(), String> {
+    self.flush_tinted(shader_program, Color::WHITE)
+  }
+
+  pub fn flush_tinted(&mut self, shader_program: &ShaderProgram, tint: Color) -> Result<(), String> {
+    if self.sprites.is_empty() {
+      return Ok(());
+    }
+
+    let mut sprites = std::mem::take(&mut self.sprites);
+
+    shader_program.bind();
+    set_model_matrix(shader_program, &Matrix4::identity())?;
+    shader_program.set_uniform(c"tint", tint)?;
+
+    sort_sprites(&mut sprites);
+    self.vertex_array.bind();
+
+    for chunk in sprites.chunks(self.capacity) {
+      self.vertex_data.clear();
+
+      for sprite in chunk {
+        self.vertex_data.extend_from_slice(&sprite.vertex_data);
+      }
+
+      self.vertex_buffer.update_vertex_data(&self.vertex_data);
+
+      for draw_call in plan_draw_calls(chunk) {
+        unsafe {
+          gl::BindTexture(gl::TEXTURE_2D, draw_call.texture_id);
+
+          gl::DrawElements(
+            gl::TRIANGLES,
+            (draw_call.sprite_count * ELEMENTS_PER_SPRITE) as gl::types::GLsizei,
+            gl::UNSIGNED_INT,
+            (draw_call.first_sprite * ELEMENTS_PER_SPRITE * std::mem::size_of::<u32>()) as *const gl::types::GLvoid
+          );
+        }
+      }
+    }
+
+    unsafe { gl::BindTexture(gl::TEXTURE_2D, 0); }
+    self.vertex_array.unbind();
+    sprites.clear();
+    self.sprites = sprites;
+
+    Ok(())
+  }
+}
+
+fn sort_sprites(sprites: &mut [BatchedSprite]) {
+  sprites.sort_by_key(|sprite| (sprite.layer, sprite.texture_id));
+}
+
+fn plan_draw_calls(sprites: &[BatchedSprite]) -> Vec<DrawCall> {
+  let mut draw_calls: Vec<DrawCall> = Vec::new();
+
+  for (index, sprite) in sprites.iter().enumerate() {
+    match draw_calls.last_mut() {
+      Some(draw_call) if draw_call.texture_id == sprite.texture_id => draw_call.sprite_count += 1,
+
+      _ => draw_calls.push(DrawCall {
+        texture_id: sprite.texture_id,
+        first_sprite: index,
+        sprite_count: 1
+      })
+    }
+  }
+
+  draw_calls
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn sprite(texture_id: gl::types::GLuint, layer: i32) -> BatchedSprite {
+    BatchedSprite {
+      texture_id,
+      layer,
+      vertex_data: [0.0; FLOATS_PER_SPRITE]
+    }
+  }
+
+  #[test]
+  fn sorting() {
+    let mut sprites = vec![sprite(2, 1), sprite(1, 1), sprite(2, 0), sprite(1, 0), sprite(2, 0)];
+
+    sort_sprites(&mut sprites);
+
+    let order: Vec<(i32, gl::types::GLuint)> = sprites.iter().map(|sprite| (sprite.layer, sprite.texture_id)).collect();
+    assert_eq!(order, vec![(0, 1), (0, 2), (0, 2), (1, 1), (1, 2)]);
+  }
+
+  #[test]
+  fn draw_call_planning() {
+    let mut sprites = vec![sprite(3, 0), sprite(1, 0), sprite(3, 0), sprite(1, 0), sprite(3, 1)];
+
+    sort_sprites(&mut sprites);
+    let draw_calls = plan_draw_calls(&sprites);
+
+    assert_eq!(draw_calls, vec![
+      DrawCall { texture_id: 1, first_sprite: 0, sprite_count: 2 },
+      DrawCall { texture_id: 3, first_sprite: 2, sprite_count: 3 }
+    ]);
+  }
+
+  #[test]
+  fn empty_planning() {
+    assert!(plan_draw_calls(&[]).is_empty());
+  }
+
+  #[test]
+  fn zero_capacity() {
+    assert!(SpriteBatch::new(0).is_err());
+  }
+}
\ No newline at end of file
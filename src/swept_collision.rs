@@ -0,0 +1,61 @@
+use crate::prelude::{ColliderHandle, CollisionDirection, Vector2};
+
+pub struct SweptCollision {
+  primary_handle: ColliderHandle,
+  secondary_handle: ColliderHandle,
+  time_of_impact: f32,
+  normal: Vector2,
+  entry_direction: CollisionDirection
+}
+
+impl SweptCollision {
+  pub fn new(primary_handle: ColliderHandle, secondary_handle: ColliderHandle, time_of_impact: f32, normal: Vector2, entry_direction: CollisionDirection) -> Self {
+    Self {
+      primary_handle,
+      secondary_handle,
+      time_of_impact,
+      normal,
+      entry_direction
+    }
+  }
+
+  pub fn primary_handle(&self) -> ColliderHandle {
+    self.primary_handle
+  }
+
+  pub fn secondary_handle(&self) -> ColliderHandle {
+    self.secondary_handle
+  }
+
+  pub fn time_of_impact(&self) -> f32 {
+    self.time_of_impact
+  }
+
+  pub fn normal(&self) -> Vector2 {
+    self.normal
+  }
+
+  pub fn entry_direction(&self) -> CollisionDirection {
+    self.entry_direction
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn constructor() {
+    let primary_handle = ColliderHandle::new(1, 0);
+    let secondary_handle = ColliderHandle::new(2, 0);
+
+    let collision = SweptCollision::new(primary_handle, secondary_handle, 0.5, Vector2::new(-1.0, 0.0), CollisionDirection::Left);
+
+    assert_eq!(collision.primary_handle, primary_handle);
+    assert_eq!(collision.secondary_handle, secondary_handle);
+    assert_eq!(collision.time_of_impact, 0.5);
+    assert_eq!(collision.normal.x, -1.0);
+    assert_eq!(collision.normal.y, 0.0);
+    assert_eq!(collision.entry_direction, CollisionDirection::Left);
+  }
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TextAlignment {
+  Left,
+  Center,
+  Right
+}
\ No newline at end of file
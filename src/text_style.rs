@@ -0,0 +1,20 @@
+use crate::prelude::{Color, TextAlignment};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TextStyle {
+  pub alignment: TextAlignment,
+  pub scale: f32,
+  pub color: Color
+}
+
+impl TextStyle {
+  pub fn new(alignment: TextAlignment, scale: f32, color: Color) -> Self {
+    Self { alignment, scale, color }
+  }
+}
+
+impl Default for TextStyle {
+  fn default() -> Self {
+    Self::new(TextAlignment::Left, 1.0, Color::WHITE)
+  }
+}
\ No newline at end of file
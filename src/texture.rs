@@ -1,5 +1,9 @@
 use std::path::Path;
 
+use image::RgbaImage;
+
+use crate::prelude::TextureFilter;
+
 pub struct Texture {
   id: gl::types::GLuint
 }
@@ -7,7 +11,11 @@ pub struct Texture {
 impl Texture {
   pub fn load(file_path: &Path) -> Result<Self, String> {
     let raw_image = image::open(file_path).map_err(|error| error.to_string())?;
-    
+
+    Ok(Self::from_image(&raw_image.to_rgba8(), TextureFilter::Linear))
+  }
+
+  pub fn from_image(raw_image: &RgbaImage, filter: TextureFilter) -> Self {
     let mut id: gl::types::GLuint = 0;
     unsafe {
       gl::GenTextures(1, &mut id);
@@ -22,15 +30,28 @@ impl Texture {
         0,
         gl::RGBA,
         gl::UNSIGNED_BYTE,
-        raw_image.as_bytes().as_ptr() as *const gl::types::GLvoid
+        raw_image.as_raw().as_ptr() as *const gl::types::GLvoid
       );
 
-      gl::GenerateMipmap(gl::TEXTURE_2D);
+      match filter {
+        TextureFilter::Linear => {
+          gl::GenerateMipmap(gl::TEXTURE_2D);
+          gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::LINEAR_MIPMAP_LINEAR as gl::types::GLint);
+          gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::LINEAR as gl::types::GLint);
+        },
+
+        TextureFilter::Nearest => {
+          gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::NEAREST as gl::types::GLint);
+          gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::NEAREST as gl::types::GLint);
+        }
+      }
     }
 
-    Ok(
-      Self { id }
-    )
+    Self { id }
+  }
+
+  pub fn id(&self) -> gl::types::GLuint {
+    self.id
   }
 
   pub fn bind(&self) {
@@ -0,0 +1,213 @@
+use std::{collections::HashMap, path::Path};
+
+use image::RgbaImage;
+
+use crate::prelude::{Rect, Sprite, Texture, TextureFilter, Vector2};
+
+const ATLAS_EXTRUSION: u32 = 1;
+
+pub struct TextureAtlas {
+  texture: Texture,
+  sprites: HashMap<String, Sprite>
+}
+
+impl TextureAtlas {
+  pub fn texture(&self) -> &Texture {
+    &self.texture
+  }
+
+  pub fn sprite(&self, name: &str) -> Option<Sprite> {
+    self.sprites.get(name).copied()
+  }
+}
+
+pub struct TextureAtlasBuilder {
+  max_width: u32,
+  images: Vec<(String, RgbaImage)>
+}
+
+impl TextureAtlasBuilder {
+  pub fn new(max_width: u32) -> Self {
+    Self {
+      max_width,
+      images: Vec::new()
+    }
+  }
+
+  pub fn add_image(&mut self, name: &str, image: RgbaImage) -> Result<(), String> {
+    if self.images.iter().any(|(existing_name, _)| existing_name == name) {
+      return Err(format!("Image '{name}' was already added to the atlas"));
+    }
+
+    self.images.push((name.to_string(), image));
+
+    Ok(())
+  }
+
+  pub fn load_image(&mut self, name: &str, file_path: &Path) -> Result<(), String> {
+    let raw_image = image::open(file_path).map_err(|error| error.to_string())?;
+    self.add_image(name, raw_image.to_rgba8())
+  }
+
+  pub fn build(self) -> Result<TextureAtlas, String> {
+    let (atlas_image, sprites) = self.pack()?;
+
+    Ok(
+      TextureAtlas {
+        texture: Texture::from_image(&atlas_image, TextureFilter::Nearest),
+        sprites
+      }
+    )
+  }
+
+  fn pack(mut self) -> Result<(RgbaImage, HashMap<String, Sprite>), String> {
+    if let Some((name, _)) = self.images.iter().find(|(_, image)| image.width() + (ATLAS_EXTRUSION * 2) > self.max_width) {
+      return Err(format!("Image '{name}' does not fit in an atlas {} pixels wide", self.max_width));
+    }
+
+    self.images.sort_by_key(|(_, image)| std::cmp::Reverse(image.height()));
+
+    let mut placements = Vec::with_capacity(self.images.len());
+    let mut cursor_x = 0;
+    let mut cursor_y = 0;
+    let mut shelf_height = 0;
+    let mut atlas_width = 0;
+
+    for (_, image) in &self.images {
+      let cell_width = image.width() + (ATLAS_EXTRUSION * 2);
+      let cell_height = image.height() + (ATLAS_EXTRUSION * 2);
+
+      if cursor_x + cell_width > self.max_width {
+        cursor_x = 0;
+        cursor_y += shelf_height;
+        shelf_height = 0;
+      }
+
+      placements.push((cursor_x + ATLAS_EXTRUSION, cursor_y + ATLAS_EXTRUSION));
+
+      cursor_x += cell_width;
+      shelf_height = shelf_height.max(cell_height);
+      atlas_width = atlas_width.max(cursor_x);
+    }
+
+    let atlas_height = cursor_y + shelf_height;
+    let mut atlas_image = RgbaImage::new(atlas_width.max(1), atlas_height.max(1));
+    let mut sprites = HashMap::with_capacity(self.images.len());
+
+    for ((name, image), (x, y)) in self.images.into_iter().zip(placements) {
+      copy_extruded(&mut atlas_image, &image, x, y);
+
+      let uv = Rect::from_corners(
+        Vector2::new(x as f32 / atlas_image.width() as f32, y as f32 / atlas_image.height() as f32),
+        Vector2::new((x + image.width()) as f32 / atlas_image.width() as f32, (y + image.height()) as f32 / atlas_image.height() as f32)
+      );
+
+      sprites.insert(name, Sprite::new(uv, image.width() as f32, image.height() as f32));
+    }
+
+    Ok((atlas_image, sprites))
+  }
+}
+
+fn copy_extruded(atlas_image: &mut RgbaImage, image: &RgbaImage, x: u32, y: u32) {
+  if image.width() == 0 || image.height() == 0 {
+    return;
+  }
+
+  for cell_y in 0..image.height() + (ATLAS_EXTRUSION * 2) {
+    for cell_x in 0..image.width() + (ATLAS_EXTRUSION * 2) {
+      let source_x = cell_x.saturating_sub(ATLAS_EXTRUSION).min(image.width() - 1);
+      let source_y = cell_y.saturating_sub(ATLAS_EXTRUSION).min(image.height() - 1);
+
+      atlas_image.put_pixel(x + cell_x - ATLAS_EXTRUSION, y + cell_y - ATLAS_EXTRUSION, *image.get_pixel(source_x, source_y));
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use image::Rgba;
+
+  fn solid_image(width: u32, height: u32, value: u8) -> RgbaImage {
+    RgbaImage::from_pixel(width, height, Rgba([value, value, value, 255]))
+  }
+
+  #[test]
+  fn packing() {
+    let mut builder = TextureAtlasBuilder::new(64);
+    builder.add_image("ball", solid_image(16, 16, 1)).unwrap();
+    builder.add_image("paddle", solid_image(16, 128, 2)).unwrap();
+    builder.add_image("power_up", solid_image(16, 16, 3)).unwrap();
+
+    let (atlas_image, sprites) = builder.pack().unwrap();
+
+    assert_eq!(atlas_image.width(), 54);
+    assert_eq!(atlas_image.height(), 130);
+
+    let paddle = sprites["paddle"];
+    assert_eq!(paddle.width(), 16.0);
+    assert_eq!(paddle.height(), 128.0);
+    assert_eq!(paddle.uv().left(), 1.0 / 54.0);
+    assert_eq!(paddle.uv().top(), 1.0 / 130.0);
+    assert_eq!(paddle.uv().bottom(), 129.0 / 130.0);
+
+    let ball = sprites["ball"];
+    assert_eq!(ball.uv().left(), 19.0 / 54.0);
+    assert_eq!(ball.uv().right(), 35.0 / 54.0);
+
+    assert_eq!(atlas_image.get_pixel(1, 1)[0], 2);
+    assert_eq!(atlas_image.get_pixel(19, 1)[0], 1);
+    assert_eq!(atlas_image.get_pixel(37, 1)[0], 3);
+    assert_eq!(atlas_image.get_pixel(18, 20)[3], 0);
+  }
+
+  #[test]
+  fn edge_extrusion() {
+    let mut image = solid_image(2, 2, 1);
+    image.put_pixel(1, 1, Rgba([9, 9, 9, 255]));
+
+    let mut builder = TextureAtlasBuilder::new(64);
+    builder.add_image("first", image).unwrap();
+    builder.add_image("second", solid_image(2, 2, 5)).unwrap();
+
+    let (atlas_image, _) = builder.pack().unwrap();
+
+    assert_eq!(atlas_image.width(), 8);
+    assert_eq!(atlas_image.get_pixel(0, 0)[0], 1);
+    assert_eq!(atlas_image.get_pixel(3, 3)[0], 9);
+    assert_eq!(atlas_image.get_pixel(3, 1)[0], 1);
+    assert_eq!(atlas_image.get_pixel(4, 0)[0], 5);
+  }
+
+  #[test]
+  fn shelf_wrapping() {
+    let mut builder = TextureAtlasBuilder::new(40);
+    builder.add_image("first", solid_image(16, 16, 1)).unwrap();
+    builder.add_image("second", solid_image(16, 8, 2)).unwrap();
+    builder.add_image("third", solid_image(16, 8, 3)).unwrap();
+
+    let (atlas_image, sprites) = builder.pack().unwrap();
+
+    assert_eq!(atlas_image.width(), 36);
+    assert_eq!(atlas_image.height(), 28);
+    assert_eq!(sprites["third"].uv().left(), 1.0 / 36.0);
+    assert_eq!(sprites["third"].uv().top(), 19.0 / 28.0);
+  }
+
+  #[test]
+  fn oversized_image() {
+    let mut builder = TextureAtlasBuilder::new(16);
+    builder.add_image("paddle", solid_image(16, 128, 1)).unwrap();
+
+    assert!(builder.pack().is_err());
+  }
+
+  #[test]
+  fn duplicate_names() {
+    let mut builder = TextureAtlasBuilder::new(64);
+    builder.add_image("ball", solid_image(16, 16, 1)).unwrap();
+
+    assert!(builder.add_image("ball", solid_image(8, 8, 2)).is_err());
+  }
+}
\ No newline at end of file
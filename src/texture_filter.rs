@@ -0,0 +1,5 @@
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TextureFilter {
+  Linear,
+  Nearest
+}
\ No newline at end of file
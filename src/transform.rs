@@ -0,0 +1,317 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use crate::prelude::{Matrix3, Matrix4, Vector2};
+
+#[derive(Clone)]
+pub struct Transform {
+  position: Vector2,
+  rotation: f32,
+  scale: Vector2,
+  parent: Option<Rc<RefCell<Transform>>>,
+  local_matrix: Cell<Option<Matrix3>>
+}
+
+impl Transform {
+  pub fn new(x: f32, y: f32) -> Self {
+    Self {
+      position: Vector2::new(x, y),
+      rotation: 0.0,
+      scale: Vector2::new(1.0, 1.0),
+      parent: None,
+      local_matrix: Cell::new(None)
+    }
+  }
+
+  pub fn interpolated(previous: &Transform, current: &Transform, alpha: f32) -> Self {
+    Self {
+      position: previous.position.lerp(current.position, alpha),
+      rotation: previous.rotation + (shortest_angle_between(previous.rotation, current.rotation) * alpha),
+      scale: previous.scale.lerp(current.scale, alpha),
+      parent: current.parent.clone(),
+      local_matrix: Cell::new(None)
+    }
+  }
+
+  pub fn x(&self) -> f32 {
+    self.position.x
+  }
+
+  pub fn y(&self) -> f32 {
+    self.position.y
+  }
+
+  pub fn position(&self) -> Vector2 {
+    self.position
+  }
+
+  pub fn rotation(&self) -> f32 {
+    self.rotation
+  }
+
+  pub fn scale(&self) -> Vector2 {
+    self.scale
+  }
+
+  pub fn parent(&self) -> Option<&Rc<RefCell<Transform>>> {
+    self.parent.as_ref()
+  }
+
+  pub fn world_position(&self) -> Vector2 {
+    self.affine().transform_point(Vector2::ZERO)
+  }
+
+  pub fn world_scale(&self) -> Vector2 {
+    let affine = self.affine();
+    let x_axis = affine.transform_vector(Vector2::new(1.0, 0.0));
+    let y_axis = affine.transform_vector(Vector2::new(0.0, 1.0));
+
+    let handedness = if (x_axis.x * y_axis.y) - (x_axis.y * y_axis.x) < 0.0 { -1.0 } else { 1.0 };
+
+    Vector2::new(x_axis.length(), y_axis.length() * handedness)
+  }
+
+  pub fn world_rotation(&self) -> f32 {
+    self.affine().transform_vector(Vector2::new(1.0, 0.0)).angle()
+  }
+
+  pub fn local_matrix(&self) -> Matrix3 {
+    if let Some(local_matrix) = self.local_matrix.get() {
+      return local_matrix;
+    }
+
+    let local_matrix = Matrix3::translation(self.position.x, self.position.y)
+      * Matrix3::rotation(self.rotation)
+      * Matrix3::scale(self.scale.x, self.scale.y);
+
+    self.local_matrix.set(Some(local_matrix));
+    local_matrix
+  }
+
+  pub fn affine(&self) -> Matrix3 {
+    match &self.parent {
+      Some(parent) => parent.borrow().affine() * self.local_matrix(),
+      None => self.local_matrix()
+    }
+  }
+
+  pub fn matrix(&self) -> Matrix4 {
+    Matrix4::from(self.affine())
+  }
+
+  pub fn translate(&mut self, translation_vector: Vector2) {
+    self.position += translation_vector;
+    self.local_matrix.set(None);
+  }
+
+  pub fn set_position(&mut self, position: Vector2) {
+    self.position = position;
+    self.local_matrix.set(None);
+  }
+
+  pub fn set_rotation(&mut self, rotation: f32) {
+    self.rotation = rotation;
+    self.local_matrix.set(None);
+  }
+
+  pub fn rotate(&mut self, angle: f32) {
+    self.rotation += angle;
+    self.local_matrix.set(None);
+  }
+
+  pub fn set_scale(&mut self, scale: Vector2) {
+    self.scale = scale;
+    self.local_matrix.set(None);
+  }
+
+  pub fn set_parent(&mut self, parent: Option<Rc<RefCell<Transform>>>) -> Result<(), String> {
+    let mut ancestor = parent.clone();
+
+    while let Some(current) = ancestor {
+      if std::ptr::eq(current.as_ptr(), self) {
+        return Err("A transform cannot be its own ancestor".to_string());
+      }
+
+      ancestor = current.borrow().parent.clone();
+    }
+
+    self.parent = parent;
+
+    Ok(())
+  }
+}
+
+fn shortest_angle_between(from: f32, to: f32) -> f32 {
+  let difference = to - from;
+
+  if difference.abs() <= std::f32::consts::PI {
+    return difference;
+  }
+
+  (difference + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU) - std::f32::consts::PI
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::prelude::Vector4;
+
+  #[test]
+  fn constructed_at() {
+    let location_x = 32.0;
+    let location_y = 64.0;
+
+    let transform = Transform::new(location_x, location_y);
+    let matrix = transform.matrix();
+
+    assert_eq!(transform.x(), location_x);
+    assert_eq!(transform.y(), location_y);
+    assert_eq!(transform.rotation(), 0.0);
+    assert_eq!(transform.scale(), Vector2::new(1.0, 1.0));
+
+    assert_eq!(matrix, Matrix4::translation(location_x, location_y, 0.0));
+  }
+
+  #[test]
+  fn translation() {
+    let location_x = 32.0;
+    let location_y = 64.0;
+    let translation_x = 32.0;
+    let translation_y = 64.0;
+    let translation_vector = Vector2::new(translation_x, translation_y);
+
+    let mut transform = Transform::new(location_x, location_y);
+    assert_eq!(transform.matrix().x.w, location_x);
+    assert_eq!(transform.matrix().y.w, location_y);
+
+    transform.translate(translation_vector);
+
+    assert_eq!(transform.x(), location_x + translation_x);
+    assert_eq!(transform.y(), location_y + translation_y);
+
+    assert_eq!(transform.matrix(), Matrix4::translation(location_x + translation_x, location_y + translation_y, 0.0));
+  }
+
+  #[test]
+  fn setting() {
+    let new_location_x = 4.0;
+    let new_location_y = 2.0;
+
+    let mut transform = Transform::new(2.0, 4.0);
+    assert_eq!(transform.matrix().x.w, 2.0);
+
+    transform.set_position(Vector2::new(new_location_x, new_location_y));
+
+    assert_eq!(transform.x(), new_location_x);
+    assert_eq!(transform.y(), new_location_y);
+
+    assert_eq!(transform.matrix(), Matrix4::translation(new_location_x, new_location_y, 0.0));
+  }
+
+  #[test]
+  fn rotation_and_scale() {
+    let mut transform = Transform::new(10.0, 20.0);
+    transform.set_scale(Vector2::new(2.0, 3.0));
+    transform.set_rotation(std::f32::consts::FRAC_PI_2);
+
+    let corner = transform.matrix() * Vector4::new(1.0, 1.0, 0.0, 1.0);
+
+    assert!((corner.x - 7.0).abs() < 0.0001);
+    assert!((corner.y - 22.0).abs() < 0.0001);
+
+    transform.rotate(-std::f32::consts::FRAC_PI_2);
+
+    let corner = transform.matrix() * Vector4::new(1.0, 1.0, 0.0, 1.0);
+
+    assert!((corner.x - 12.0).abs() < 0.0001);
+    assert!((corner.y - 23.0).abs() < 0.0001);
+  }
+
+  #[test]
+  fn parenting() {
+    let parent = Rc::new(RefCell::new(Transform::new(100.0, 50.0)));
+    parent.borrow_mut().set_scale(Vector2::new(2.0, 2.0));
+
+    let mut transform = Transform::new(10.0, 0.0);
+    transform.set_parent(Some(Rc::clone(&parent))).unwrap();
+
+    assert_eq!(transform.position(), Vector2::new(10.0, 0.0));
+    assert_eq!(transform.world_position(), Vector2::new(120.0, 50.0));
+    assert_eq!(transform.world_scale(), Vector2::new(2.0, 2.0));
+
+    parent.borrow_mut().translate(Vector2::new(0.0, 10.0));
+
+    assert_eq!(transform.world_position(), Vector2::new(120.0, 60.0));
+    assert_eq!(transform.matrix().y.w, 60.0);
+
+    transform.set_parent(None).unwrap();
+
+    assert!(transform.parent().is_none());
+    assert_eq!(transform.world_position(), Vector2::new(10.0, 0.0));
+  }
+
+  #[test]
+  fn world_values_under_scaled_parent() {
+    let parent = Rc::new(RefCell::new(Transform::new(0.0, 0.0)));
+    parent.borrow_mut().set_scale(Vector2::new(2.0, 1.0));
+
+    let mut transform = Transform::new(0.0, 0.0);
+    transform.set_rotation(std::f32::consts::FRAC_PI_2);
+    transform.set_parent(Some(parent)).unwrap();
+
+    let world_scale = transform.world_scale();
+
+    assert!((transform.world_rotation() - std::f32::consts::FRAC_PI_2).abs() < 0.0001);
+    assert!((world_scale.x - 1.0).abs() < 0.0001);
+    assert!((world_scale.y - 2.0).abs() < 0.0001);
+  }
+
+  #[test]
+  fn interpolation() {
+    let previous = Transform::new(0.0, 8.0);
+    let mut current = Transform::new(16.0, 4.0);
+    current.set_rotation(1.0);
+    current.set_scale(Vector2::new(3.0, 1.0));
+
+    let start = Transform::interpolated(&previous, &current, 0.0);
+    let middle = Transform::interpolated(&previous, &current, 0.5);
+    let end = Transform::interpolated(&previous, &current, 1.0);
+
+    assert_eq!(start.x(), 0.0);
+    assert_eq!(start.y(), 8.0);
+
+    assert_eq!(middle.x(), 8.0);
+    assert_eq!(middle.y(), 6.0);
+    assert_eq!(middle.rotation(), 0.5);
+    assert_eq!(middle.scale(), Vector2::new(2.0, 1.0));
+
+    assert_eq!(end.x(), 16.0);
+    assert_eq!(end.y(), 4.0);
+  }
+
+  #[test]
+  fn interpolation_takes_shortest_rotation() {
+    let mut previous = Transform::new(0.0, 0.0);
+    previous.set_rotation(3.0);
+
+    let mut current = Transform::new(0.0, 0.0);
+    current.set_rotation(-3.0);
+
+    let middle = Transform::interpolated(&previous, &current, 0.5);
+
+    assert!((middle.rotation() - (3.0 + (std::f32::consts::PI - 3.0))).abs() < 0.0001);
+  }
+
+  #[test]
+  fn parent_cycles_rejected() {
+    let first = Rc::new(RefCell::new(Transform::new(0.0, 0.0)));
+    let second = Rc::new(RefCell::new(Transform::new(0.0, 0.0)));
+
+    second.borrow_mut().set_parent(Some(Rc::clone(&first))).unwrap();
+
+    assert!(first.borrow_mut().set_parent(Some(Rc::clone(&second))).is_err());
+    assert!(first.borrow_mut().set_parent(Some(Rc::clone(&first))).is_err());
+    assert!(first.borrow().parent().is_none());
+    assert_eq!(second.borrow().world_position(), Vector2::new(0.0, 0.0));
+  }
+}
\ No newline at end of file
@@ -0,0 +1,56 @@
+use crate::prelude::{Color, Matrix3, Matrix4, Vector2, Vector3, Vector4};
+
+pub trait UniformValue {
+  fn upload(&self, location: gl::types::GLint);
+}
+
+impl UniformValue for f32 {
+  fn upload(&self, location: gl::types::GLint) {
+    unsafe { gl::Uniform1f(location, *self); }
+  }
+}
+
+impl UniformValue for i32 {
+  fn upload(&self, location: gl::types::GLint) {
+    unsafe { gl::Uniform1i(location, *self); }
+  }
+}
+
+impl UniformValue for Vector2 {
+  fn upload(&self, location: gl::types::GLint) {
+    unsafe { gl::Uniform2f(location, self.x, self.y); }
+  }
+}
+
+impl UniformValue for Vector3 {
+  fn upload(&self, location: gl::types::GLint) {
+    unsafe { gl::Uniform3f(location, self.x, self.y, self.z); }
+  }
+}
+
+impl UniformValue for Vector4 {
+  fn upload(&self, location: gl::types::GLint) {
+    unsafe { gl::Uniform4f(location, self.x, self.y, self.z, self.w); }
+  }
+}
+
+impl UniformValue for Color {
+  fn upload(&self, location: gl::types::GLint) {
+    unsafe { gl::Uniform4f(location, self.r, self.g, self.b, self.a); }
+  }
+}
+
+impl UniformValue for Matrix3 {
+  fn upload(&self, location: gl::types::GLint) {
+    let (x, y, z) = (self.column(0), self.column(1), self.column(2));
+    let column_major: [f32; 9] = [x.x, x.y, x.z, y.x, y.y, y.z, z.x, z.y, z.z];
+
+    unsafe { gl::UniformMatrix3fv(location, 1, gl::FALSE, column_major.as_ptr()); }
+  }
+}
+
+impl UniformValue for Matrix4 {
+  fn upload(&self, location: gl::types::GLint) {
+    unsafe { gl::UniformMatrix4fv(location, 1, gl::FALSE, self.to_column_major().as_ptr()); }
+  }
+}
\ No newline at end of file
@@ -5,7 +5,7 @@ pub struct VertexArray {
 }
 
 impl VertexArray {
-  pub fn textured(vertex_buffer: BufferObject, element_buffer: BufferObject) -> Self {
+  pub fn textured_colored(vertex_buffer: &BufferObject, element_buffer: &BufferObject) -> Self {
     let mut id: gl::types::GLuint = 0;
 
     unsafe {
@@ -20,7 +20,7 @@ impl VertexArray {
         2,
         gl::FLOAT,
         gl::FALSE,
-        (4 * std::mem::size_of::<f32>()) as gl::types::GLint,
+        (8 * std::mem::size_of::<f32>()) as gl::types::GLint,
         std::ptr::null()
       );
 
@@ -30,7 +30,52 @@ impl VertexArray {
         2,
         gl::FLOAT,
         gl::FALSE,
-        (4 * std::mem::size_of::<f32>()) as gl::types::GLint,
+        (8 * std::mem::size_of::<f32>()) as gl::types::GLint,
+        (2 * std::mem::size_of::<f32>()) as *const gl::types::GLvoid
+      );
+
+      gl::EnableVertexAttribArray(2);
+      gl::VertexAttribPointer(
+        2,
+        4,
+        gl::FLOAT,
+        gl::FALSE,
+        (8 * std::mem::size_of::<f32>()) as gl::types::GLint,
+        (4 * std::mem::size_of::<f32>()) as *const gl::types::GLvoid
+      );
+
+      gl::BindBuffer(gl::ARRAY_BUFFER, 0);
+      gl::BindVertexArray(0);
+    }
+
+    Self { id }
+  }
+
+  pub fn colored(vertex_buffer: &BufferObject) -> Self {
+    let mut id: gl::types::GLuint = 0;
+
+    unsafe {
+      gl::GenVertexArrays(1, &mut id);
+      gl::BindVertexArray(id);
+      gl::BindBuffer(gl::ARRAY_BUFFER, vertex_buffer.id());
+
+      gl::EnableVertexAttribArray(0);
+      gl::VertexAttribPointer(
+        0,
+        2,
+        gl::FLOAT,
+        gl::FALSE,
+        (6 * std::mem::size_of::<f32>()) as gl::types::GLint,
+        std::ptr::null()
+      );
+
+      gl::EnableVertexAttribArray(1);
+      gl::VertexAttribPointer(
+        1,
+        4,
+        gl::FLOAT,
+        gl::FALSE,
+        (6 * std::mem::size_of::<f32>()) as gl::types::GLint,
         (2 * std::mem::size_of::<f32>()) as *const gl::types::GLvoid
       );
 
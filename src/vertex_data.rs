@@ -1,46 +1,70 @@
-pub fn generate_textured_vertex_data(width: u32, height: u32) -> (Vec<f32>, Vec<i32>) {
-  let hw = width as f32 / 2.0;
-  let hh = height as f32 / 2.0;
-
-  (
-    vec![
-      -hw,  hh,  0.0, 0.0,
-       hw,  hh,  1.0, 0.0,
-       hw, -hh,  1.0, 1.0,
-      -hw, -hh,  0.0, 1.0
-    ],
-
-    vec![
-      0, 1, 2,
-      0, 2, 3
-    ]
-  )
+use crate::prelude::{Color, Matrix3, Rect, Vector2};
+
+pub fn generate_sprite_vertex_data(matrix: &Matrix3, width: f32, height: f32, uv: Rect, color: Color) -> [f32; 32] {
+  let hw = width / 2.0;
+  let hh = height / 2.0;
+
+  let top_left = matrix.transform_point(Vector2::new(-hw, -hh));
+  let top_right = matrix.transform_point(Vector2::new(hw, -hh));
+  let bottom_right = matrix.transform_point(Vector2::new(hw, hh));
+  let bottom_left = matrix.transform_point(Vector2::new(-hw, hh));
+
+  let Color { r, g, b, a } = color;
+
+  [
+    top_left.x, top_left.y, uv.left(), uv.top(), r, g, b, a,
+    top_right.x, top_right.y, uv.right(), uv.top(), r, g, b, a,
+    bottom_right.x, bottom_right.y, uv.right(), uv.bottom(), r, g, b, a,
+    bottom_left.x, bottom_left.y, uv.left(), uv.bottom(), r, g, b, a
+  ]
+}
+
+pub fn generate_batch_element_data(sprite_count: usize) -> Vec<i32> {
+  let mut element_data = Vec::with_capacity(sprite_count * 6);
+
+  for sprite_index in 0..sprite_count as i32 {
+    let first_vertex = sprite_index * 4;
+
+    element_data.extend_from_slice(&[
+      first_vertex, first_vertex + 1, first_vertex + 2,
+      first_vertex, first_vertex + 2, first_vertex + 3
+    ]);
+  }
+
+  element_data
 }
 
 #[cfg(test)]
 mod tests {
-  use super::generate_textured_vertex_data;
+  use super::*;
 
   #[test]
-  fn generated_data() {
-    let width = 64;
-    let height = 64;
-
-    let (
-      vertex_data,
-      element_data
-    ) = generate_textured_vertex_data(width, height);
-
-    assert_eq!(vertex_data, vec![
-      -32.0,  32.0, 0.0, 0.0,
-       32.0,  32.0, 1.0, 0.0,
-       32.0, -32.0, 1.0, 1.0,
-      -32.0, -32.0, 0.0, 1.0
+  fn sprite_data() {
+    let matrix = Matrix3::translation(100.0, 50.0);
+
+    let uv = Rect::from_corners(Vector2::new(0.25, 0.5), Vector2::new(0.75, 1.0));
+
+    let color = Color::new(0.1, 0.2, 0.3, 0.4);
+
+    let vertex_data = generate_sprite_vertex_data(&matrix, 64.0, 32.0, uv, color);
+
+    assert_eq!(vertex_data, [
+       68.0, 34.0, 0.25, 0.5, 0.1, 0.2, 0.3, 0.4,
+      132.0, 34.0, 0.75, 0.5, 0.1, 0.2, 0.3, 0.4,
+      132.0, 66.0, 0.75, 1.0, 0.1, 0.2, 0.3, 0.4,
+       68.0, 66.0, 0.25, 1.0, 0.1, 0.2, 0.3, 0.4
     ]);
+  }
+
+  #[test]
+  fn batch_element_data() {
+    let element_data = generate_batch_element_data(2);
 
     assert_eq!(element_data, vec![
       0, 1, 2,
-      0, 2, 3
+      0, 2, 3,
+      4, 5, 6,
+      4, 6, 7
     ]);
   }
 }
\ No newline at end of file